@@ -0,0 +1,55 @@
+// benches/entropy_pool.rs
+// Pooled vs direct OS entropy for many small tokens
+// Run with: cargo bench --features rand,std --bench entropy_pool
+// (without `std`, `generate` makes one OS request per call)
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::TryRngCore;
+use secure_gate::rng::{DynamicRng, EntropyPool, FixedRng};
+
+fn bench_os_direct(c: &mut Criterion) {
+    c.bench_function("OsRng 16 bytes (syscall per call)", |b| {
+        b.iter(|| {
+            let mut bytes = [0u8; 16];
+            rand::rngs::OsRng.try_fill_bytes(&mut bytes).unwrap();
+            black_box(bytes)
+        })
+    });
+}
+
+fn bench_fixed_generate(c: &mut Criterion) {
+    c.bench_function("FixedRng::<16>::generate (thread pool)", |b| {
+        b.iter(|| black_box(FixedRng::<16>::generate()))
+    });
+}
+
+fn bench_fixed_pooled(c: &mut Criterion) {
+    let mut pool = EntropyPool::new();
+    c.bench_function("EntropyPool::generate::<16>", |b| {
+        b.iter(|| black_box(pool.generate::<16>()))
+    });
+}
+
+fn bench_dynamic_generate(c: &mut Criterion) {
+    c.bench_function("DynamicRng::generate(32) (thread pool)", |b| {
+        b.iter(|| black_box(DynamicRng::generate(32)))
+    });
+}
+
+fn bench_dynamic_pooled(c: &mut Criterion) {
+    let mut pool = EntropyPool::new();
+    c.bench_function("EntropyPool::generate_dynamic(32)", |b| {
+        b.iter(|| black_box(pool.generate_dynamic(32)))
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_os_direct,
+    bench_fixed_generate,
+    bench_fixed_pooled,
+    bench_dynamic_generate,
+    bench_dynamic_pooled,
+);
+
+criterion_main!(benches);
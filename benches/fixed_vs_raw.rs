@@ -90,12 +90,14 @@ fn bench_fixed_construction(c: &mut Criterion) {
 
 // === Zeroize overhead (when enabled) ===
 
+// `Fixed` has no `Drop`, so a plain drop never wipes — the zeroize path goes
+// through `into_zeroizing`, whose `Zeroizing` wrapper wipes on drop.
 #[cfg(feature = "zeroize")]
 fn bench_fixed_drop_with_zeroize(c: &mut Criterion) {
     c.bench_function("Fixed<[u8; 32]> drop (zeroize enabled)", |b| {
         b.iter(|| {
-            let key = Fixed::new([42u8; 32]);
-            drop(key); // Should zeroize
+            let key = Fixed::new([42u8; 32]).into_zeroizing();
+            drop(black_box(key)); // Zeroizing wipes on drop
         })
     });
 }
@@ -105,7 +107,7 @@ fn bench_fixed_drop_without_zeroize(c: &mut Criterion) {
     c.bench_function("Fixed<[u8; 32]> drop (zeroize disabled)", |b| {
         b.iter(|| {
             let key = Fixed::new([42u8; 32]);
-            drop(key); // No zeroization
+            black_box(key); // No `Drop` — nothing to wipe
         })
     });
 }
@@ -85,8 +85,11 @@ fuzz_target!(|data: &[u8]| {
         let new_size = v.len().saturating_add(data.len().min(500_000));
         v.resize(new_size, 0xFF);
         v.truncate(data.len().saturating_add(1) % 3000);
+        #[cfg(not(feature = "zeroize"))]
         v.retain(|&b| b != data[0]);
     }
+    #[cfg(feature = "zeroize")]
+    bytes.retain_secure(|b| b != data[0]);
 
     #[cfg(feature = "zeroize")]
     if data[0] % 3 == 0 {
@@ -0,0 +1,141 @@
+// ==========================================================================
+// src/aligned.rs
+// ==========================================================================
+
+//! Fixed-size byte secrets with a guaranteed minimum alignment.
+//!
+//! `Fixed<[u8; N]>` is only byte-aligned. SIMD and some crypto backends need the key
+//! buffer on a 16-, 32- or 64-byte boundary; [`AlignedFixed`] provides that while keeping
+//! the redacted `Debug` and zeroize behaviour.
+//!
+//! Rust cannot take an alignment as a const generic, so the boundary is chosen with a
+//! marker type: [`Align16`], [`Align32`] or [`Align64`].
+
+use core::fmt;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Alignment marker for [`AlignedFixed`]. Sealed — use [`Align16`], [`Align32`] or [`Align64`].
+pub trait Alignment: sealed::Sealed {}
+
+macro_rules! alignment_marker {
+    ($name:ident, $align:literal) => {
+        #[doc = concat!("Alignment marker: ", $align, "-byte boundary.")]
+        #[repr(align($align))]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl sealed::Sealed for $name {}
+        impl Alignment for $name {}
+    };
+}
+
+alignment_marker!(Align16, 16);
+alignment_marker!(Align32, 32);
+alignment_marker!(Align64, 64);
+
+/// Stack-allocated `[u8; N]` secret aligned to the boundary of `A`.
+///
+/// The zero-length `[A; 0]` field takes no space but raises the struct's alignment
+/// to `A`'s, and `repr(C)` places the bytes at offset 0 — so the buffer itself is aligned.
+///
+/// With `zeroize`, the bytes are wiped on drop.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::{aligned::Align32, AlignedFixed};
+/// let key = AlignedFixed::<32, Align32>::new([0x42; 32]);
+/// assert_eq!(key.expose_secret().as_ptr() as usize % 32, 0);
+/// assert_eq!(format!("{key:?}"), "[REDACTED]");
+/// ```
+#[repr(C)]
+pub struct AlignedFixed<const N: usize, A: Alignment> {
+    bytes: [u8; N],
+    _align: [A; 0],
+}
+
+impl<const N: usize, A: Alignment> AlignedFixed<N, A> {
+    /// Wrap a byte array in an aligned secret.
+    #[inline(always)]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self { bytes, _align: [] }
+    }
+
+    /// Expose the inner bytes for read-only access.
+    ///
+    /// This is the **only** way to read the secret — loud and auditable.
+    #[inline(always)]
+    pub const fn expose_secret(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Expose the inner bytes for mutable access.
+    ///
+    /// This is the **only** way to mutate the secret — loud and auditable.
+    #[inline(always)]
+    pub fn expose_secret_mut(&mut self) -> &mut [u8; N] {
+        &mut self.bytes
+    }
+
+    /// Returns the fixed length in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the secret is zero-length.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize, A: Alignment> AlignedFixed<N, A> {
+    /// Explicitly zeroize the secret immediately.
+    #[inline]
+    pub fn zeroize_now(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bytes);
+    }
+}
+
+impl<const N: usize, A: Alignment> From<[u8; N]> for AlignedFixed<N, A> {
+    #[inline(always)]
+    fn from(bytes: [u8; N]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+// Explicit Clone only — no implicit Copy
+impl<const N: usize, A: Alignment> Clone for AlignedFixed<N, A> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::new(self.bytes)
+    }
+}
+
+impl<const N: usize, A: Alignment> fmt::Debug for AlignedFixed<N, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize, A: Alignment> zeroize::Zeroize for AlignedFixed<N, A> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bytes);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize, A: Alignment> zeroize::ZeroizeOnDrop for AlignedFixed<N, A> {}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize, A: Alignment> Drop for AlignedFixed<N, A> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bytes);
+    }
+}
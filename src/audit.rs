@@ -0,0 +1,93 @@
+// ==========================================================================
+// src/audit.rs
+// ==========================================================================
+
+//! Global audit hook for secret exposure.
+//!
+//! Once a hook is registered, every `Dynamic::expose_secret` / `expose_secret_mut` /
+//! `expose_secret_guarded` / `as_box` / `as_box_mut` / `disarm`, each `Dynamic` →
+//! `Zeroizing` conversion, and `SharedSecret::expose_secret` /
+//! `AuditedDynamicVec::expose_secret` call invokes it with the inner type's name (from
+//! `core::any::type_name`).
+//! The hook only ever receives that label — never a reference to the secret.
+//!
+//! `audit` also adds `Dynamic::expose_secret_guarded`, a read guard that `debug_assert!`s
+//! if the same secret is exposed again on the same thread while the guard is alive — a
+//! diagnostic for logic bugs, compiled out of release builds.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "audit")]
+//! # {
+//! use core::sync::atomic::{AtomicUsize, Ordering};
+//! use secure_gate::{audit, Dynamic};
+//!
+//! static EXPOSURES: AtomicUsize = AtomicUsize::new(0);
+//! audit::set_expose_hook(|_label| {
+//!     EXPOSURES.fetch_add(1, Ordering::Relaxed);
+//! });
+//!
+//! let pw: Dynamic<String> = "hunter2".into();
+//! let _ = pw.expose_secret();
+//! assert!(EXPOSURES.load(Ordering::Relaxed) >= 1);
+//! audit::clear_expose_hook();
+//! # }
+//! ```
+
+use core::cell::RefCell;
+use std::sync::RwLock;
+use std::vec::Vec;
+
+static EXPOSE_HOOK: RwLock<Option<fn(&'static str)>> = RwLock::new(None);
+
+/// Register `hook` to be called on every `Dynamic` exposure, replacing any previous hook.
+pub fn set_expose_hook(hook: fn(&'static str)) {
+    *EXPOSE_HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(hook);
+}
+
+/// Remove the registered hook, if any.
+pub fn clear_expose_hook() {
+    *EXPOSE_HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+// The lock is released before the hook runs, so a hook may itself expose secrets
+// (or swap the hook) without deadlocking.
+#[inline]
+pub(crate) fn on_expose<T: ?Sized>() {
+    let hook = *EXPOSE_HOOK.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(hook) = hook {
+        hook(core::any::type_name::<T>());
+    }
+}
+
+// ── Re-entrancy detection for `Dynamic::expose_secret_guarded` ───────
+//
+// Addresses of the secrets currently held by an `ExposureGuard` on this thread. A
+// diagnostic only: checks are `debug_assert!`s, compiled out of release builds.
+
+std::thread_local! {
+    static GUARDED: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) const NESTED_EXPOSURE: &str =
+    "nested exposure of a secret that is already held by an ExposureGuard on this thread";
+
+#[inline]
+pub(crate) fn is_guarded(addr: usize) -> bool {
+    GUARDED.with(|g| g.borrow().contains(&addr))
+}
+
+pub(crate) fn enter_guard(addr: usize) {
+    debug_assert!(!is_guarded(addr), "{NESTED_EXPOSURE}");
+    GUARDED.with(|g| g.borrow_mut().push(addr));
+}
+
+pub(crate) fn exit_guard(addr: usize) {
+    GUARDED.with(|g| {
+        let mut g = g.borrow_mut();
+        if let Some(i) = g.iter().rposition(|&a| a == addr) {
+            g.swap_remove(i);
+        }
+    });
+}
@@ -0,0 +1,186 @@
+// ==========================================================================
+// src/builder.rs
+// ==========================================================================
+
+//! Build-then-freeze assembly of heap secrets.
+//!
+//! [`SecretBuilder`] accumulates a secret in several steps — every growth goes through the
+//! secure path (fresh allocation + wipe of the old one under `zeroize`) — and
+//! [`finish`](SecretBuilder::finish) locks it down as a [`DynamicNoClone`], with excess
+//! capacity shrunk away and wiped.
+
+use crate::dynamic::{secure_reserve_string, secure_reserve_vec};
+use crate::DynamicNoClone;
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+mod sealed {
+    pub trait Sealed {
+        fn shrink_for_finish(&mut self);
+        #[cfg(feature = "zeroize")]
+        fn wipe(&mut self);
+    }
+}
+
+/// Buffer types a [`SecretBuilder`] can assemble: `Vec<u8>` and `String`. Sealed.
+pub trait BuilderBuffer: sealed::Sealed + Default {}
+
+impl sealed::Sealed for Vec<u8> {
+    fn shrink_for_finish(&mut self) {
+        #[cfg(feature = "zeroize")]
+        crate::dynamic::shrink_and_wipe_vec(self);
+        #[cfg(not(feature = "zeroize"))]
+        self.shrink_to_fit();
+    }
+
+    #[cfg(feature = "zeroize")]
+    fn wipe(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+impl BuilderBuffer for Vec<u8> {}
+
+impl sealed::Sealed for String {
+    fn shrink_for_finish(&mut self) {
+        #[cfg(feature = "zeroize")]
+        crate::dynamic::shrink_and_wipe_string(self);
+        #[cfg(not(feature = "zeroize"))]
+        self.shrink_to_fit();
+    }
+
+    #[cfg(feature = "zeroize")]
+    fn wipe(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+impl BuilderBuffer for String {}
+
+/// Accumulates a secret, then freezes it into a non-cloneable [`DynamicNoClone`].
+///
+/// The builder never exposes its contents and cannot be cloned. With `zeroize`, growth
+/// never leaves stale copies behind, and a builder dropped without `finish` wipes its buffer.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::SecretBuilder;
+/// let mut b = SecretBuilder::<Vec<u8>>::new();
+/// b.extend_from_slice(b"salt:");
+/// b.push(0x42);
+/// let secret = b.finish();
+/// assert_eq!(secret.expose_secret(), b"salt:\x42");
+/// ```
+pub struct SecretBuilder<T: BuilderBuffer>(T);
+
+impl<T: BuilderBuffer> SecretBuilder<T> {
+    /// Start an empty builder. Does not allocate.
+    #[inline]
+    pub fn new() -> Self {
+        Self(T::default())
+    }
+
+    /// Freeze the accumulated secret: shrink the capacity to the length (wiping the old
+    /// allocation under `zeroize`) and return it as an immutable, non-cloneable secret.
+    ///
+    /// The result cannot be cloned:
+    ///
+    /// ```compile_fail
+    /// use secure_gate::SecretBuilder;
+    /// let secret = SecretBuilder::<Vec<u8>>::new().finish();
+    /// let _copy = secret.clone(); // error: no `clone` on `DynamicNoClone`
+    /// ```
+    #[inline]
+    pub fn finish(mut self) -> DynamicNoClone<T> {
+        let mut buf = core::mem::take(&mut self.0);
+        sealed::Sealed::shrink_for_finish(&mut buf);
+        DynamicNoClone::new(Box::new(buf))
+    }
+}
+
+impl SecretBuilder<Vec<u8>> {
+    /// Start a builder with room for `capacity` bytes, so no growth (and no copy) is needed
+    /// up to that size.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Append one byte.
+    #[inline]
+    pub fn push(&mut self, byte: u8) {
+        secure_reserve_vec(&mut self.0, 1);
+        self.0.push(byte);
+    }
+
+    /// Append a slice of bytes.
+    #[inline]
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        secure_reserve_vec(&mut self.0, bytes.len());
+        self.0.extend_from_slice(bytes);
+    }
+
+    /// Number of bytes accumulated so far.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if nothing has been accumulated.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl SecretBuilder<String> {
+    /// Start a builder with room for `capacity` bytes of UTF-8.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(String::with_capacity(capacity))
+    }
+
+    /// Append one character.
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        secure_reserve_string(&mut self.0, ch.len_utf8());
+        self.0.push(ch);
+    }
+
+    /// Append a string slice.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        secure_reserve_string(&mut self.0, s.len());
+        self.0.push_str(s);
+    }
+
+    /// Number of bytes accumulated so far.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if nothing has been accumulated.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: BuilderBuffer> Default for SecretBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: BuilderBuffer> fmt::Debug for SecretBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: BuilderBuffer> Drop for SecretBuilder<T> {
+    fn drop(&mut self) {
+        sealed::Sealed::wipe(&mut self.0);
+    }
+}
@@ -4,103 +4,549 @@
 
 #![cfg_attr(not(feature = "zeroize"), forbid(unsafe_code))]
 
-#[cfg(feature = "conversions")]
-use alloc::string::String;
-#[cfg(feature = "conversions")]
+//! Explicit encodings and constant-time comparisons for secret bytes.
+//!
+//! The module is split by allocation needs:
+//! - Allocation-free (pure `no_std`): `ct_eq`, `ct_is_zero`, `write_hex`.
+//! - Requires `alloc`: the `String`-returning encoders (`to_hex`, `to_hex_upper`,
+//!   `to_base64url`) and the `HexString` / `RandomHex` wrappers.
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
-#[cfg(feature = "conversions")]
+#[cfg(feature = "alloc")]
 use base64::Engine;
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
 use zeroize::Zeroize;
 
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for [u8] {}
+    impl<const N: usize> Sealed for [u8; N] {}
+}
+
 /// Extension trait for safe, explicit conversions of secret byte data.
 ///
 /// All methods require the caller to first call `.expose_secret()` (or `.expose_secret_mut()`).
 /// This makes every secret access loud, grep-able, and auditable.
 ///
+/// The comparison methods and [`write_hex`](Self::write_hex) never allocate and are
+/// available without the `alloc` feature; the `String` encoders require `alloc`.
+///
+/// Sealed — implemented for `[u8]` and `[u8; N]` only, so the method set can vary
+/// with features and grow without breaking downstream code.
+///
 /// # Example
 ///
 /// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
 /// # use secure_gate::{fixed_alias, SecureConversionsExt};
 /// fixed_alias!(pub Aes256Key, 32);  // Visibility required
 /// let key = Aes256Key::from([0x42u8; 32]);
 /// let hex = key.expose_secret().to_hex();         // → "424242..."
 /// let b64 = key.expose_secret().to_base64url();   // URL-safe, no padding
 /// # assert_eq!(hex, "4242424242424242424242424242424242424242424242424242424242424242");
+/// # }
 /// ```
-#[cfg(feature = "conversions")]
-pub trait SecureConversionsExt {
+pub trait SecureConversionsExt: sealed::Sealed {
     /// Encode secret bytes as lowercase hexadecimal.
+    #[cfg(feature = "alloc")]
     fn to_hex(&self) -> String;
 
     /// Encode secret bytes as uppercase hexadecimal.
+    #[cfg(feature = "alloc")]
     fn to_hex_upper(&self) -> String;
 
     /// Encode secret bytes as URL-safe base64 (no padding).
+    #[cfg(feature = "alloc")]
     fn to_base64url(&self) -> String;
 
+    /// Encode as lowercase hex, returning the encoding already wrapped as a secret.
+    ///
+    /// The encoded `String`'s buffer is moved into the `Dynamic` — never copied — so the
+    /// hex form gets the same redacted `Debug` and wiping as the raw bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::SecureConversionsExt;
+    /// let hex = [0xde, 0xad].to_hex_secret();
+    /// assert_eq!(hex.expose_secret(), "dead");
+    /// assert_eq!(format!("{hex:?}"), "[REDACTED]");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn to_hex_secret(&self) -> crate::Dynamic<String> {
+        crate::Dynamic::new(self.to_hex())
+    }
+
+    /// Encode as URL-safe base64 (no padding), returning the encoding wrapped as a secret.
+    ///
+    /// Same guarantees as [`to_hex_secret`](Self::to_hex_secret).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn to_base64url_secret(&self) -> crate::Dynamic<String> {
+        crate::Dynamic::new(self.to_base64url())
+    }
+
+    /// Encode secret bytes as lowercase hexadecimal into a caller buffer — no allocation.
+    ///
+    /// Writes `2 * len` bytes to the front of `out` and returns them as `&str`.
+    /// Returns `Err` if `out` is shorter than `2 * len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::SecureConversionsExt;
+    /// let mut buf = [0u8; 8];
+    /// let hex = [0xde, 0xad, 0xbe, 0xef].write_hex(&mut buf)?;
+    /// assert_eq!(hex, "deadbeef");
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    fn write_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str, &'static str>;
+
     /// Constant-time equality comparison.
     ///
     /// Returns `true` if the two secrets are equal, `false` otherwise.
     /// Uses `subtle::ConstantTimeEq` under the hood – safe against timing attacks.
     fn ct_eq(&self, other: &Self) -> bool;
+
+    /// Constant-time check that every byte is zero (e.g. an unset or wiped key).
+    fn ct_is_zero(&self) -> bool;
+}
+
+#[inline(always)]
+fn write_hex_into<'a>(bytes: &[u8], out: &'a mut [u8]) -> Result<&'a str, &'static str> {
+    let hex_len = bytes
+        .len()
+        .checked_mul(2)
+        .filter(|&n| n <= out.len())
+        .ok_or("hex output buffer too small")?;
+    let out = &mut out[..hex_len];
+    hex::encode_to_slice(bytes, out).map_err(|_| "hex output buffer too small")?;
+    Ok(core::str::from_utf8(out).expect("hex output is always ASCII"))
+}
+
+#[inline(always)]
+fn ct_is_zero_bytes(bytes: &[u8]) -> bool {
+    let acc = bytes.iter().fold(0u8, |acc, &b| acc | b);
+    subtle::ConstantTimeEq::ct_eq(&acc, &0u8).into()
 }
 
-#[cfg(feature = "conversions")]
 impl SecureConversionsExt for [u8] {
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     fn to_hex(&self) -> String {
         hex::encode(self)
     }
 
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     fn to_hex_upper(&self) -> String {
         hex::encode_upper(self)
     }
 
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     fn to_base64url(&self) -> String {
         URL_SAFE_NO_PAD.encode(self)
     }
 
+    #[inline(always)]
+    fn write_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str, &'static str> {
+        write_hex_into(self, out)
+    }
+
     #[inline(always)]
     fn ct_eq(&self, other: &Self) -> bool {
         subtle::ConstantTimeEq::ct_eq(self, other).into()
     }
+
+    #[inline(always)]
+    fn ct_is_zero(&self) -> bool {
+        ct_is_zero_bytes(self)
+    }
 }
 
-#[cfg(feature = "conversions")]
 impl<const N: usize> SecureConversionsExt for [u8; N] {
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     fn to_hex(&self) -> String {
         hex::encode(self)
     }
 
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     fn to_hex_upper(&self) -> String {
         hex::encode_upper(self)
     }
 
+    #[cfg(feature = "alloc")]
     #[inline(always)]
     fn to_base64url(&self) -> String {
         URL_SAFE_NO_PAD.encode(self)
     }
 
+    #[inline(always)]
+    fn write_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str, &'static str> {
+        write_hex_into(self, out)
+    }
+
     #[inline(always)]
     fn ct_eq(&self, other: &Self) -> bool {
         subtle::ConstantTimeEq::ct_eq(self.as_slice(), other.as_slice()).into()
     }
+
+    #[inline(always)]
+    fn ct_is_zero(&self) -> bool {
+        ct_is_zero_bytes(self)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Integer views of small fixed secrets (big-endian)
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// For 8- and 16-byte secrets that must be fed to an API taking a single word. The
+// returned integer is an unprotected copy — keep it short-lived.
+
+impl crate::Fixed<[u8; 16]> {
+    /// Read the 16 bytes as a **big-endian** `u128` (`bytes[0]` is the most significant).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut bytes = [0u8; 16];
+    /// bytes[15] = 1;
+    /// assert_eq!(Fixed::new(bytes).to_u128_be(), 1);
+    /// ```
+    #[inline(always)]
+    pub fn to_u128_be(&self) -> u128 {
+        u128::from_be_bytes(*self.expose_secret())
+    }
+
+    /// Store a `u128` as 16 **big-endian** bytes.
+    #[inline(always)]
+    pub const fn from_u128_be(value: u128) -> Self {
+        Self::new(value.to_be_bytes())
+    }
+}
+
+impl crate::Fixed<[u8; 8]> {
+    /// Read the 8 bytes as a **big-endian** `u64` (`bytes[0]` is the most significant).
+    #[inline(always)]
+    pub fn to_u64_be(&self) -> u64 {
+        u64::from_be_bytes(*self.expose_secret())
+    }
+
+    /// Store a `u64` as 8 **big-endian** bytes.
+    #[inline(always)]
+    pub const fn from_u64_be(value: u64) -> Self {
+        Self::new(value.to_be_bytes())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Branchless masks — building blocks for constant-time selection
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<const N: usize> crate::Fixed<[u8; N]> {
+    /// `0xFF` if the byte at `index` is nonzero, `0x00` if it is zero — computed without
+    /// branching on the byte's value.
+    ///
+    /// Use the mask to select between *public* values (`(a & mask) | (b & !mask)`)
+    /// based on a secret byte. `index` itself must be public: it is used directly for
+    /// the lookup, and an out-of-range index panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let flags = Fixed::new([0x00, 0x01, 0x80]);
+    /// assert_eq!(flags.byte_as_mask(0), 0x00);
+    /// assert_eq!(flags.byte_as_mask(1), 0xFF);
+    ///
+    /// let mask = flags.byte_as_mask(2);
+    /// let chosen = (10u8 & mask) | (20u8 & !mask);
+    /// assert_eq!(chosen, 10);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn byte_as_mask(&self, index: usize) -> u8 {
+        use subtle::{ConditionallySelectable, ConstantTimeEq};
+        let is_zero = self.expose_secret()[index].ct_eq(&0);
+        u8::conditional_select(&0xFF, &0x00, is_zero)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Constant-time table lookup
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Return `table[index]` without a secret-dependent memory access — for S-box-style
+/// lookups where `index` is secret.
+///
+/// Every one of the 256 entries is read and conditionally selected, so the access
+/// pattern (and thus cache timing) is the same for every index. Costs 256 selects per
+/// lookup instead of one load.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "conversions")]
+/// # {
+/// use secure_gate::conversions::ct_lookup;
+/// let sbox: [u8; 256] = core::array::from_fn(|i| (i as u8).rotate_left(3) ^ 0x63);
+/// assert_eq!(ct_lookup(&sbox, 0x2A), sbox[0x2A]);
+/// # }
+/// ```
+pub fn ct_lookup(table: &[u8; 256], index: u8) -> u8 {
+    use subtle::{ConditionallySelectable, ConstantTimeEq};
+    let mut out = 0u8;
+    for (i, entry) in (0..=u8::MAX).zip(table) {
+        out.conditional_assign(entry, i.ct_eq(&index));
+    }
+    out
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// GF(2^128) multiplication (GHASH)
+// ─────────────────────────────────────────────────────────────────────────────
+
+// GCM's reduction constant: x^128 = x^7 + x^2 + x + 1, in reflected bit order.
+const GCM_R: u128 = 0xE1 << 120;
+
+impl crate::Fixed<[u8; 16]> {
+    /// Multiply two field elements in GF(2^128), reduced modulo the GCM polynomial
+    /// `x^128 + x^7 + x^2 + x + 1` — the core operation of GHASH and similar
+    /// polynomial MACs.
+    ///
+    /// Uses GCM's reflected convention (NIST SP 800-38D, §6.3): byte 0 holds the lowest
+    /// coefficients, and within each byte the **most** significant bit is the lowest
+    /// coefficient. So bit 7 of byte 0 is the coefficient of `x^0`, and the field's `1` is
+    /// `[0x80, 0, …, 0]`.
+    ///
+    /// Constant-time: all 128 steps run unconditionally, with additions and reductions
+    /// applied through masks rather than branches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let h = Fixed::new([0x42u8; 16]);
+    /// let mut one = [0u8; 16];
+    /// one[0] = 0x80;
+    /// assert_eq!(h.gf128_mul(&Fixed::new(one)).expose_secret(), h.expose_secret());
+    /// # }
+    /// ```
+    pub fn gf128_mul(&self, other: &Self) -> Self {
+        let x = u128::from_be_bytes(*self.expose_secret());
+        let mut v = u128::from_be_bytes(*other.expose_secret());
+        let mut z = 0u128;
+        for i in (0..128).rev() {
+            z ^= v & 0u128.wrapping_sub((x >> i) & 1);
+            v = (v >> 1) ^ (GCM_R & 0u128.wrapping_sub(v & 1));
+        }
+        Self::new(z.to_be_bytes())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// X25519 scalar clamping
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl crate::Fixed<[u8; 32]> {
+    /// Clamp a little-endian X25519 scalar in place (RFC 7748, §5).
+    ///
+    /// Exactly three changes, in the scalar's little-endian bit numbering:
+    /// - bits 0, 1, 2 cleared — `byte[0] &= 0xF8`, making the scalar a multiple of the
+    ///   cofactor 8;
+    /// - bit 255 cleared — `byte[31] &= 0x7F`;
+    /// - bit 254 set — `byte[31] |= 0x40`.
+    ///
+    /// All other bits are untouched, and clamping is idempotent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let mut scalar = Fixed::new([0xFFu8; 32]);
+    /// scalar.clamp_x25519();
+    /// assert_eq!(scalar.expose_secret()[0], 0xF8);
+    /// assert_eq!(scalar.expose_secret()[31], 0x7F);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn clamp_x25519(&mut self) {
+        let bytes = self.expose_secret_mut();
+        bytes[0] &= 0xF8;
+        bytes[31] &= 0x7F;
+        bytes[31] |= 0x40;
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// `subtle` trait interop
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lets `Fixed` secrets flow into generic code bounded by `subtle::ConstantTimeEq`.
+///
+/// Agrees with the inherent [`Fixed::ct_eq`](crate::Fixed::ct_eq), which returns a plain
+/// `bool` and still wins in method-call syntax; call the trait form explicitly to keep
+/// the `Choice`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "conversions")]
+/// # {
+/// use secure_gate::Fixed;
+/// use subtle::ConstantTimeEq;
+///
+/// fn same<T: ConstantTimeEq>(a: &T, b: &T) -> bool {
+///     a.ct_eq(b).into()
+/// }
+/// assert!(same(&Fixed::new([1u8; 32]), &Fixed::new([1u8; 32])));
+/// # }
+/// ```
+impl<const N: usize> subtle::ConstantTimeEq for crate::Fixed<[u8; N]> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        subtle::ConstantTimeEq::ct_eq(
+            self.expose_secret().as_slice(),
+            other.expose_secret().as_slice(),
+        )
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Loggable fingerprints
+// ─────────────────────────────────────────────────────────────────────────────
+
+// Domain separation: fingerprints never equal any other BLAKE3 use of the same bytes.
+#[cfg(feature = "fingerprint")]
+const FINGERPRINT_CONTEXT: &str = "secure-gate Fixed::fingerprint v1";
+
+#[cfg(feature = "fingerprint")]
+impl<const N: usize> crate::Fixed<[u8; N]> {
+    /// A short, non-reversible tag for correlating log lines without logging the secret.
+    ///
+    /// Returns 8 lowercase hex characters: the first 32 bits of a domain-separated
+    /// BLAKE3 hash (`derive_key` mode) of the bytes. Identical secrets always produce
+    /// the same fingerprint, so it is safe to log — but understand what it does and does
+    /// not promise:
+    ///
+    /// - **Preimage:** recovering a high-entropy key (e.g. 32 random bytes) from 32 bits
+    ///   is hopeless. A *low-entropy* secret (PIN, short password) can be brute-forced
+    ///   against its fingerprint offline — do not fingerprint those.
+    /// - **Collisions:** with 32 bits, two different secrets share a fingerprint with
+    ///   probability 2⁻³², and a collision among ~65 000 distinct secrets is likely.
+    ///   Use it to tell keys apart in logs, never as an identifier or integrity check.
+    ///
+    /// Requires the `fingerprint` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "fingerprint")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([0x42u8; 32]);
+    /// let fp = key.fingerprint();
+    /// assert_eq!(fp.len(), 8);
+    /// assert_eq!(fp, Fixed::new([0x42u8; 32]).fingerprint());
+    /// # }
+    /// ```
+    pub fn fingerprint(&self) -> String {
+        let digest = blake3::derive_key(FINGERPRINT_CONTEXT, self.expose_secret());
+        hex::encode(&digest[..4])
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Keys from environment variables
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "std")]
+impl<const N: usize> crate::Fixed<[u8; N]> {
+    /// Read a hex-encoded key from the environment variable `var`.
+    ///
+    /// The hex decodes straight into the stack array, and the value returned by
+    /// `std::env::var` is zeroized (under `zeroize`) on every path — including the raw
+    /// `OsString` carried by a not-valid-unicode error. That is the only copy
+    /// we control: the process environment block — and whatever set it (shell history,
+    /// `/proc/<pid>/environ`, a service manager) — keeps the value beyond our reach.
+    /// Prefer a secrets file or vault when that matters.
+    ///
+    /// # Errors
+    ///
+    /// - `"environment variable not set"`
+    /// - `"environment variable is not valid unicode"`
+    /// - `"hex string length mismatch"` — not exactly `2 * N` characters
+    /// - `"invalid hex string"` — right length, but a non-hex character
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "std", feature = "conversions"))]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// std::env::set_var("DOC_EXAMPLE_KEY", "deadbeef");
+    /// let key = Fixed::<[u8; 4]>::from_env_hex("DOC_EXAMPLE_KEY")?;
+    /// assert_eq!(key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+    /// # }
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    pub fn from_env_hex(var: &str) -> Result<Self, &'static str> {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut value = std::env::var(var).map_err(|e| match e {
+            std::env::VarError::NotPresent => "environment variable not set",
+            std::env::VarError::NotUnicode(raw) => {
+                #[cfg(feature = "zeroize")]
+                zeroize::Zeroize::zeroize(&mut raw.into_encoded_bytes());
+                #[cfg(not(feature = "zeroize"))]
+                drop(raw);
+                "environment variable is not valid unicode"
+            }
+        })?;
+
+        let result = if value.len() != 2 * N {
+            Err("hex string length mismatch")
+        } else {
+            let mut arr = [0u8; N];
+            match hex::decode_to_slice(value.as_bytes(), &mut arr) {
+                Ok(()) => Ok(Self::new(arr)),
+                Err(_) => {
+                    #[cfg(feature = "zeroize")]
+                    zeroize::Zeroize::zeroize(&mut arr);
+                    Err("invalid hex string")
+                }
+            }
+        };
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut value);
+        result
+    }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
 // HexString — validated, lowercase hex wrapper
 // ─────────────────────────────────────────────────────────────────────────────
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "alloc")]
 #[derive(Clone, Debug)]
 pub struct HexString(crate::Dynamic<String>);
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "alloc")]
 impl HexString {
     /// Create a new `HexString` from a `String`, validating it in-place.
     ///
@@ -127,26 +573,16 @@ impl HexString {
     /// ```
     pub fn new(mut s: String) -> Result<Self, &'static str> {
         // Fast early check – hex strings must have even length
-        if s.len() % 2 != 0 {
+        if !s.len().is_multiple_of(2) {
             zeroize_input(&mut s);
             return Err("invalid hex string");
         }
 
-        // Work directly on the underlying bytes – no copies
-        // SAFETY: `String::as_mut_vec()` is unstable but safe. We only modify bytes
-        // within the string's valid UTF-8 range, and we validate all changes maintain
-        // UTF-8 validity (only ASCII hex digits are modified).
-        let bytes = unsafe { s.as_mut_vec() };
-        let mut valid = true;
-        for b in bytes.iter_mut() {
-            match *b {
-                b'A'..=b'F' => *b += 32, // 'A' → 'a'
-                b'a'..=b'f' | b'0'..=b'9' => {}
-                _ => valid = false,
-            }
-        }
+        // Scan every byte (no early exit), then normalize in place – no copies
+        let valid = s.bytes().fold(true, |ok, b| ok & b.is_ascii_hexdigit());
 
         if valid {
+            s.make_ascii_lowercase(); // 'A'..='F' → 'a'..='f'
             Ok(Self(crate::Dynamic::new(s)))
         } else {
             zeroize_input(&mut s);
@@ -163,13 +599,14 @@ impl HexString {
 
     /// Number of bytes the decoded hex string represents.
     pub const fn byte_len(&self) -> usize {
-        self.0.expose_secret().len() / 2
+        self.0.len() / 2
     }
 }
 
 // Private helper – wipes rejected input when `zeroize` is enabled
-#[cfg(feature = "conversions")]
+#[cfg(feature = "alloc")]
 #[inline(always)]
+#[cfg_attr(not(feature = "zeroize"), allow(unused_variables, clippy::ptr_arg))]
 fn zeroize_input(s: &mut String) {
     #[cfg(feature = "zeroize")]
     {
@@ -179,7 +616,7 @@ fn zeroize_input(s: &mut String) {
     }
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "alloc")]
 impl core::ops::Deref for HexString {
     type Target = crate::Dynamic<String>;
     fn deref(&self) -> &Self::Target {
@@ -188,7 +625,7 @@ impl core::ops::Deref for HexString {
 }
 
 // Manual constant-time equality – prevents timing attacks on hex strings
-#[cfg(feature = "conversions")]
+#[cfg(feature = "alloc")]
 impl PartialEq for HexString {
     fn eq(&self, other: &Self) -> bool {
         self.0
@@ -198,18 +635,78 @@ impl PartialEq for HexString {
     }
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(feature = "alloc")]
 impl Eq for HexString {}
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Protected decoding — `Dynamic<String>` in, `Dynamic<Vec<u8>>` out
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "alloc")]
+impl crate::Dynamic<Vec<u8>> {
+    /// Decode a protected base64url (no padding) string into a protected byte buffer.
+    ///
+    /// Decodes straight into the output allocation — sized up front, so it never
+    /// reallocates — and no plaintext copy escapes. On error the partially decoded
+    /// buffer is wiped (with `zeroize`) before it is freed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err("invalid base64url string")` on malformed input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::{Dynamic, SecureConversionsExt};
+    /// let encoded = [0xde, 0xad, 0xbe, 0xef].to_base64url_secret();
+    /// let bytes = Dynamic::<Vec<u8>>::from_base64url_secret(&encoded)?;
+    /// assert_eq!(bytes.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    pub fn from_base64url_secret(encoded: &crate::Dynamic<String>) -> Result<Self, &'static str> {
+        let input = encoded.expose_secret();
+        let mut out = Vec::with_capacity(base64::decoded_len_estimate(input.len()));
+        match URL_SAFE_NO_PAD.decode_vec(input, &mut out) {
+            Ok(()) => Ok(Self::new(out)),
+            Err(_) => {
+                // `Vec::zeroize` wipes the full capacity, including any partial output
+                #[cfg(feature = "zeroize")]
+                out.zeroize();
+                Err("invalid base64url string")
+            }
+        }
+    }
+
+    /// Decode a protected hex string into a protected byte buffer.
+    ///
+    /// Same guarantees as [`from_base64url_secret`](Self::from_base64url_secret).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err("invalid hex string")` on odd length or non-hex characters.
+    pub fn from_hex_secret(encoded: &crate::Dynamic<String>) -> Result<Self, &'static str> {
+        let input = encoded.expose_secret();
+        let mut out = alloc::vec![0u8; input.len() / 2];
+        match hex::decode_to_slice(input, &mut out) {
+            Ok(()) => Ok(Self::new(out)),
+            Err(_) => {
+                #[cfg(feature = "zeroize")]
+                out.zeroize();
+                Err("invalid hex string")
+            }
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // RandomHex — only constructible from fresh RNG
 // ─────────────────────────────────────────────────────────────────────────────
 
-#[cfg(all(feature = "rand", feature = "conversions"))]
+#[cfg(all(feature = "rand", feature = "alloc"))]
 #[derive(Clone, Debug)]
 pub struct RandomHex(HexString);
 
-#[cfg(all(feature = "rand", feature = "conversions"))]
+#[cfg(all(feature = "rand", feature = "alloc"))]
 impl RandomHex {
     /// Internal constructor – only called by `FixedRng<N>::random_hex()`.
     pub(crate) fn new_fresh(hex: HexString) -> Self {
@@ -227,7 +724,7 @@ impl RandomHex {
     }
 }
 
-#[cfg(all(feature = "rand", feature = "conversions"))]
+#[cfg(all(feature = "rand", feature = "alloc"))]
 impl core::ops::Deref for RandomHex {
     type Target = HexString;
     fn deref(&self) -> &Self::Target {
@@ -235,22 +732,23 @@ impl core::ops::Deref for RandomHex {
     }
 }
 
-#[cfg(all(feature = "rand", feature = "conversions"))]
+#[cfg(all(feature = "rand", feature = "alloc"))]
 impl PartialEq for RandomHex {
     fn eq(&self, other: &Self) -> bool {
         self.0.eq(&other.0)
     }
 }
 
-#[cfg(all(feature = "rand", feature = "conversions"))]
+#[cfg(all(feature = "rand", feature = "alloc"))]
 impl Eq for RandomHex {}
 
-#[cfg(all(feature = "rand", feature = "conversions"))]
+#[cfg(all(feature = "rand", feature = "alloc"))]
 impl<const N: usize> crate::rng::FixedRng<N> {
     /// Generate a fresh random value and immediately return it as a validated,
     /// lower-case hex string.
     ///
-    /// The intermediate random bytes are zeroized as soon as the hex string is created.
+    /// With `zeroize`, the intermediate random bytes are wiped as soon as the hex string
+    /// is created. The returned `RandomHex` is not wiped on drop (see [`Dynamic`](crate::Dynamic)).
     ///
     /// # Example
     ///
@@ -262,9 +760,14 @@ impl<const N: usize> crate::rng::FixedRng<N> {
     /// ```
     pub fn random_hex() -> RandomHex {
         let hex = {
-            let fresh_rng = Self::generate();
-            hex::encode(fresh_rng.expose_secret())
-        }; // fresh_rng dropped and zeroized here
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            let mut fresh: crate::Fixed<[u8; N]> = Self::generate().into();
+            let hex = hex::encode(fresh.expose_secret());
+            // `Fixed` has no `Drop`, so wipe the raw bytes explicitly
+            #[cfg(feature = "zeroize")]
+            fresh.zeroize_now();
+            hex
+        };
         RandomHex::new_fresh(HexString(crate::Dynamic::new(hex)))
     }
 }
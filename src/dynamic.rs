@@ -2,9 +2,9 @@
 // src/dynamic.rs
 // ==========================================================================
 
-extern crate alloc;
-
 use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Heap-allocated secure secret wrapper.
 ///
@@ -14,7 +14,11 @@ use alloc::boxed::Box;
 /// Security invariants:
 /// - No `Deref` or `AsRef` — prevents silent access.
 /// - `Debug` is always redacted.
-/// - With `zeroize`, wipes the entire allocation on drop (including spare capacity).
+/// - With `zeroize`, `zeroize_now` wipes the entire allocation (including spare capacity).
+///
+/// Like [`Fixed`](crate::Fixed), `Dynamic` has no `Drop` of its own: dropping it frees
+/// the buffer without wiping it. Wipe explicitly, or hand the value to
+/// `zeroize::Zeroizing` to get wipe-on-drop.
 ///
 /// # Examples
 ///
@@ -33,15 +37,26 @@ use alloc::boxed::Box;
 /// assert_eq!(secret.expose_secret(), "pass!");
 /// ```
 ///
-/// With `zeroize` (automatic wipe):
+/// With `zeroize` (explicit wipe):
 /// ```
 /// # #[cfg(feature = "zeroize")]
 /// # {
 /// use secure_gate::Dynamic;
-/// let secret = Dynamic::<Vec<u8>>::new(vec![1u8; 32]);
-/// drop(secret); // heap wiped automatically
+/// let mut secret = Dynamic::<Vec<u8>>::new(vec![1u8; 32]);
+/// secret.zeroize_now(); // heap wiped in place
+/// drop(secret);
+///
+/// let scoped: zeroize::Zeroizing<Vec<u8>> = Dynamic::new(vec![2u8; 32]).into();
+/// drop(scoped); // wiped by `Zeroizing`'s drop
 /// # }
 /// ```
+///
+/// `Dynamic<T>` is `ZeroizeOnDrop` only when `T` is:
+///
+/// ```compile_fail
+/// fn assert_zod<T: zeroize::ZeroizeOnDrop>() {}
+/// assert_zod::<secure_gate::Dynamic<Vec<u8>>>(); // not wiped on drop
+/// ```
 pub struct Dynamic<T: ?Sized>(Box<T>);
 
 impl<T: ?Sized> Dynamic<T> {
@@ -67,8 +82,23 @@ impl<T: ?Sized> Dynamic<T> {
     /// Expose the inner value for read-only access.
     ///
     /// This is the **only** way to read the secret — loud and auditable.
+    /// With `audit`, notifies the [`audit`](crate::audit) hook, if one is registered,
+    /// and in debug builds panics if the secret is currently held by an
+    /// `ExposureGuard` on this thread.
+    ///
+    /// Not a `const fn` — with or without `audit` — so enabling the feature anywhere in
+    /// the dependency graph never changes which call sites compile.
     #[inline(always)]
-    pub const fn expose_secret(&self) -> &T {
+    pub fn expose_secret(&self) -> &T {
+        #[cfg(feature = "audit")]
+        {
+            crate::audit::on_expose::<T>();
+            debug_assert!(
+                !self.guard_addr().is_some_and(crate::audit::is_guarded),
+                "{}",
+                crate::audit::NESTED_EXPOSURE
+            );
+        }
         &self.0
     }
 
@@ -77,9 +107,146 @@ impl<T: ?Sized> Dynamic<T> {
     /// This is the **only** way to mutate the secret — loud and auditable.
     #[inline(always)]
     pub fn expose_secret_mut(&mut self) -> &mut T {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
+        &mut self.0
+    }
+
+    /// Expose the secret's `Box` itself, for APIs that take `&Box<T>`.
+    ///
+    /// Same access as [`expose_secret`](Self::expose_secret), named for the box it hands
+    /// out. Notifies the `audit` hook like the other exposures.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// fn boxed_len(b: &Box<[u8]>) -> usize { b.len() }
+    /// let secret: Dynamic<[u8]> = Dynamic::new_boxed(vec![1u8, 2, 3].into_boxed_slice());
+    /// assert_eq!(boxed_len(secret.as_box()), 3);
+    /// ```
+    #[allow(clippy::borrowed_box)]
+    #[inline(always)]
+    pub fn as_box(&self) -> &Box<T> {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
+        &self.0
+    }
+
+    /// Expose the secret's `Box` mutably.
+    ///
+    /// Assigning a new box through this reference drops the old one **without** wiping
+    /// it — mutate through the box, or zeroize before replacing it.
+    #[inline(always)]
+    pub fn as_box_mut(&mut self) -> &mut Box<T> {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
         &mut self.0
     }
 
+    /// Returns `true` if both handles point at the same heap allocation.
+    ///
+    /// A debugging aid: distinct `Dynamic`s should never alias, so this is `false` for
+    /// a value and its clone. Zero-sized `T` never allocates, so all such handles compare equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let a = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    /// assert!(a.ptr_eq(&a));
+    /// assert!(!a.ptr_eq(&a.clone()));
+    /// ```
+    #[inline(always)]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        core::ptr::eq(&*self.0, &*other.0)
+    }
+
+
+    /// Crate-internal: hand the box to another secure wrapper without exposing it.
+    #[cfg(any(feature = "secrecy", feature = "bytes"))]
+    #[inline(always)]
+    pub(crate) fn into_box(self) -> Box<T> {
+        self.0
+    }
+
+    /// Give up the wrapper and take ownership of the raw `Box<T>` — e.g. to hand the
+    /// buffer to C code that will free it.
+    ///
+    /// Nothing is wiped: `Dynamic` has no drop-time logic of its own, so moving the box
+    /// out leaves the bytes exactly as they are and nothing fires for the wrapper. From
+    /// here on the caller owns the secret *and* the duty to wipe it.
+    ///
+    /// This is the escape hatch from the explicit-exposure model (with
+    /// [`leak_static`](Self::leak_static) built on it) — keep it rare and grep for
+    /// `.disarm(` in audits. With the `audit` feature it notifies the hook.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let secret = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    /// let raw: Box<Vec<u8>> = secret.disarm();
+    /// assert_eq!(*raw, [1, 2, 3]); // no longer protected
+    /// ```
+    #[inline(always)]
+    pub fn disarm(self) -> Box<T> {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
+        self.0
+    }
+
+    /// Leak the secret for the rest of the process and return a `'static` reference —
+    /// for a config secret (e.g. an API key) loaded once at startup.
+    ///
+    /// **Opts out of wiping for good**: the allocation is never freed, so it is never
+    /// zeroized, and the bytes stay in memory (and in any core dump) until the process
+    /// exits. In exchange, call sites read the value without re-exposing a wrapper.
+    /// Built on [`disarm`](Self::disarm), so it notifies the `audit` hook once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// static API_KEY: std::sync::OnceLock<&'static str> = std::sync::OnceLock::new();
+    ///
+    /// let loaded: Dynamic<String> = "sk-live-123".into(); // e.g. read from a vault
+    /// let key: &'static String = loaded.leak_static();
+    /// API_KEY.set(key.as_str()).unwrap();
+    /// assert_eq!(API_KEY.get(), Some(&"sk-live-123"));
+    /// ```
+    #[inline]
+    pub fn leak_static(self) -> &'static T
+    where
+        T: 'static,
+    {
+        Box::leak(self.disarm())
+    }
+
+    /// Move the secret into a `Pin<Box<T>>` — no re-boxing, the allocation is reused.
+    ///
+    /// **Opts out of wiping**: like [`disarm`](Self::disarm), this hands over the raw
+    /// box un-wiped, and a plain `Pin<Box<T>>` is freed without a wipe. Wipe it through
+    /// the pin (or bring it back with [`from_pinned`](Self::from_pinned)) before dropping.
+    /// Notifies the `audit` hook, since the secret leaves the gate.
+    ///
+    /// Works for any `T`. Getting the secret back out requires `T: Unpin`; a `!Unpin`
+    /// value stays pinned for good.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let secret = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    /// let pinned = secret.into_pinned();
+    /// assert_eq!(&**pinned, &[1, 2, 3]);
+    /// ```
+    #[inline(always)]
+    pub fn into_pinned(self) -> core::pin::Pin<Box<T>> {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
+        Box::into_pin(self.0)
+    }
 
     /// Convert to a non-cloneable variant.
     ///
@@ -99,6 +266,76 @@ impl<T: ?Sized> Dynamic<T> {
     }
 }
 
+impl<T: Clone> Dynamic<T> {
+    /// Clone into an independent, non-cloneable copy.
+    ///
+    /// `self` stays cloneable; the copy can be handed to a subsystem that must not
+    /// duplicate it further.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let source = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    /// let mut copy = source.clone_no_clone();
+    /// copy.expose_secret_mut()[0] = 9;
+    /// assert_eq!(source.expose_secret(), &[1, 2, 3]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use secure_gate::Dynamic;
+    /// let copy = Dynamic::<Vec<u8>>::new(vec![1]).clone_no_clone();
+    /// let _again = copy.clone(); // error: `DynamicNoClone` cannot be cloned
+    /// ```
+    #[inline]
+    pub fn clone_no_clone(&self) -> crate::DynamicNoClone<T> {
+        crate::DynamicNoClone::new(Box::new((*self.0).clone()))
+    }
+}
+
+impl<T: ?Sized + Unpin> Dynamic<T> {
+    /// Take a secret back out of a `Pin<Box<T>>` without re-boxing.
+    ///
+    /// Requires `T: Unpin` — unpinning a `!Unpin` value could move it while something
+    /// relies on its address, so there is deliberately no unchecked variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let pinned = Box::pin(String::from("hunter2"));
+    /// let secret = Dynamic::from_pinned(pinned);
+    /// assert_eq!(secret.expose_secret(), "hunter2");
+    /// ```
+    #[inline(always)]
+    pub fn from_pinned(pinned: core::pin::Pin<Box<T>>) -> Self {
+        Dynamic(core::pin::Pin::into_inner(pinned))
+    }
+}
+
+impl<T: Default> Dynamic<T> {
+    /// Take the secret out, leaving `T::default()` in its place — like `Option::take`.
+    ///
+    /// The secret's allocation moves with the returned wrapper; nothing is copied. For
+    /// `Vec` and `String` the value left behind is empty with zero capacity, so no stale
+    /// bytes remain in `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut slot = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    /// let taken = slot.take();
+    /// assert_eq!(taken.expose_secret(), &[1, 2, 3]);
+    /// assert!(slot.is_empty());
+    /// assert_eq!(slot.expose_secret().capacity(), 0);
+    /// ```
+    #[inline]
+    pub fn take(&mut self) -> Self {
+        Dynamic(core::mem::take(&mut self.0))
+    }
+}
+
 // Explicit zeroization — only available with `zeroize` feature
 #[cfg(feature = "zeroize")]
 impl<T: ?Sized + zeroize::Zeroize> Dynamic<T> {
@@ -122,6 +359,69 @@ impl<T: ?Sized + zeroize::Zeroize> Dynamic<T> {
     pub fn zeroize_now(&mut self) {
         self.0.zeroize();
     }
+
+    /// Run `f` on the secret mutably; if `f` panics, wipe the secret before unwinding on.
+    ///
+    /// A panic halfway through an update can leave the secret half-modified. Here the
+    /// secret is zeroized during the unwind instead, so no inconsistent value survives
+    /// for a `catch_unwind` caller (or a later drop) to observe. With `panic = "abort"`
+    /// there is no unwind and nothing to do. Notifies the `audit` hook like
+    /// `expose_secret_mut`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut secret = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    /// let sum: u32 = secret.with_secret_mut(|v| {
+    ///     v.push(4);
+    ///     v.iter().map(|&b| u32::from(b)).sum()
+    /// });
+    /// assert_eq!(sum, 10);
+    /// # }
+    /// ```
+    pub fn with_secret_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
+        let mut guard = WipeOnUnwind {
+            secret: &mut *self.0,
+            armed: true,
+        };
+        let result = f(&mut *guard.secret);
+        guard.armed = false;
+        result
+    }
+}
+
+// Zeroizes the secret if dropped while still armed — i.e. during a panic in the closure.
+#[cfg(feature = "zeroize")]
+struct WipeOnUnwind<'a, T: ?Sized + zeroize::Zeroize> {
+    secret: &'a mut T,
+    armed: bool,
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: ?Sized + zeroize::Zeroize> Drop for WipeOnUnwind<'_, T> {
+    fn drop(&mut self) {
+        if self.armed {
+            self.secret.zeroize();
+        }
+    }
+}
+
+// Without `zeroize` there is nothing to wipe with: a plain scoped mutable exposure.
+#[cfg(not(feature = "zeroize"))]
+impl<T: ?Sized> Dynamic<T> {
+    /// Run `f` on the secret mutably and return its result.
+    ///
+    /// With `zeroize`, a panic in `f` wipes the secret before unwinding; without it this
+    /// is equivalent to `f(self.expose_secret_mut())`.
+    #[inline]
+    pub fn with_secret_mut<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(self.expose_secret_mut())
+    }
 }
 
 impl<T: ?Sized> core::fmt::Debug for Dynamic<T> {
@@ -131,6 +431,15 @@ impl<T: ?Sized> core::fmt::Debug for Dynamic<T> {
 }
 
 // Clone impls — gated correctly
+//
+// Both delegate to `T::clone`. For `Vec<u8>` / `String` that copies exactly the `len`
+// initialized bytes into a fresh `len`-capacity buffer: spare capacity — which may
+// still hold stale bytes from an earlier, longer value — is never read or carried over.
+
+/// Deep-copies the secret into a new allocation.
+///
+/// For `Vec` / `String`, only the live contents are copied: the clone's capacity equals
+/// its length, so stale bytes in the source's spare capacity never reach the clone.
 #[cfg(not(feature = "zeroize"))]
 impl<T: Clone> Clone for Dynamic<T> {
     #[inline(always)]
@@ -139,6 +448,10 @@ impl<T: Clone> Clone for Dynamic<T> {
     }
 }
 
+/// Deep-copies the secret into a new allocation.
+///
+/// For `Vec` / `String`, only the live contents are copied: the clone's capacity equals
+/// its length, so stale bytes in the source's spare capacity never reach the clone.
 #[cfg(feature = "zeroize")]
 impl<T: Clone + zeroize::Zeroize> Clone for Dynamic<T> {
     #[inline(always)]
@@ -148,12 +461,26 @@ impl<T: Clone + zeroize::Zeroize> Clone for Dynamic<T> {
 }
 
 // === Ergonomic helpers for common heap types ===
+//
+// `Dynamic` has no `Deref`, so these length queries are inherent methods — safe public
+// metadata that never exposes the contents.
 impl Dynamic<String> {
+    /// Length of the secret in bytes.
+    ///
+    /// Inherent, not reached through `Deref` — `Dynamic` has none, so no other `String`
+    /// methods leak through:
+    ///
+    /// ```compile_fail
+    /// use secure_gate::Dynamic;
+    /// let pw: Dynamic<String> = "hunter2".into();
+    /// let _ = pw.as_str(); // error: no `str` methods through `Dynamic`
+    /// ```
     #[inline(always)]
     pub const fn len(&self) -> usize {
         self.0.len()
     }
 
+    /// Returns `true` if the secret is empty.
     #[inline(always)]
     pub const fn is_empty(&self) -> bool {
         self.0.is_empty()
@@ -161,86 +488,1431 @@ impl Dynamic<String> {
 }
 
 impl<T> Dynamic<Vec<T>> {
+    /// Number of elements in the secret.
     #[inline(always)]
     pub const fn len(&self) -> usize {
         self.0.len()
     }
 
+    /// Returns `true` if the secret holds no elements.
     #[inline(always)]
     pub const fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 }
 
-// === Convenient From impls ===
-impl<T> From<T> for Dynamic<T> {
-    #[inline(always)]
-    fn from(value: T) -> Self {
-        Self(Box::new(value))
+impl Dynamic<Vec<u8>> {
+    /// Transform every byte of the secret in place — no copy, no second allocation.
+    ///
+    /// Timing depends on `f`; keep it constant-time if the transform is secret-dependent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut secret = Dynamic::<Vec<u8>>::new(b"key".to_vec());
+    /// secret.map_bytes_in_place(|b| b.to_ascii_uppercase());
+    /// assert_eq!(secret.expose_secret(), b"KEY");
+    /// ```
+    #[inline]
+    pub fn map_bytes_in_place(&mut self, mut f: impl FnMut(u8) -> u8) {
+        for b in self.0.iter_mut() {
+            *b = f(*b);
+        }
     }
-}
 
-impl<T: ?Sized> From<Box<T>> for Dynamic<T> {
-    #[inline(always)]
-    fn from(boxed: Box<T>) -> Self {
-        Self(boxed)
+    /// Overwrite every byte (up to `len()`) with `byte`.
+    ///
+    /// Spare capacity is untouched. For wiping, prefer `zeroize_now`, which the compiler
+    /// cannot optimize away and which also clears the spare capacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut secret = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    /// secret.fill(0xFF);
+    /// assert_eq!(secret.expose_secret(), &[0xFF; 3]);
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, byte: u8) {
+        self.0.fill(byte);
     }
-}
 
-impl From<&str> for Dynamic<String> {
-    #[inline(always)]
-    fn from(s: &str) -> Self {
-        Self(Box::new(s.to_string()))
+    /// Copy `src` into `self`, reusing `self`'s allocation when it is large enough.
+    ///
+    /// Unlike `*self = src.clone()`, the old buffer is never dropped un-wiped: with
+    /// `zeroize`, bytes past `src.len()` are wiped before truncation, and if `self` must
+    /// grow, its entire old allocation is zeroized before being replaced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut dest = Dynamic::<Vec<u8>>::new(b"long-lived secret".to_vec());
+    /// let src = Dynamic::<Vec<u8>>::new(b"short".to_vec());
+    /// let cap = dest.expose_secret().capacity();
+    /// dest.clone_from_secure(&src);
+    /// assert_eq!(dest.expose_secret(), b"short");
+    /// assert_eq!(dest.expose_secret().capacity(), cap);
+    /// ```
+    pub fn clone_from_secure(&mut self, src: &Self) {
+        let dst = &mut *self.0;
+        let src = src.0.as_slice();
+        if dst.capacity() < src.len() {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(dst);
+            *dst = src.to_vec();
+            return;
+        }
+        #[cfg(feature = "zeroize")]
+        if dst.len() > src.len() {
+            zeroize::Zeroize::zeroize(&mut dst[src.len()..]);
+        }
+        dst.clear();
+        dst.extend_from_slice(src);
     }
-}
 
-// Constant-time equality — only available with `conversions` feature
-#[cfg(feature = "conversions")]
-impl<T> Dynamic<T>
-where
-    T: ?Sized + AsRef<[u8]>,
-{
+    /// Grow the capacity once so that `additional` more bytes fit without reallocating.
+    ///
+    /// Unlike `reserve_exact`, whose `realloc` frees the old buffer without wiping it, with
+    /// `zeroize` this copies into a fresh allocation and wipes the old one. No-op if the
+    /// spare capacity already suffices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut secret = Dynamic::<Vec<u8>>::new(b"key".to_vec());
+    /// secret.reserve_exact_secure(29);
+    /// assert!(secret.expose_secret().capacity() >= 32);
+    /// ```
     #[inline]
-    pub fn ct_eq(&self, other: &Self) -> bool {
-        use crate::conversions::SecureConversionsExt;
-        self.expose_secret()
-            .as_ref()
-            .ct_eq(other.expose_secret().as_ref())
+    pub fn reserve_exact_secure(&mut self, additional: usize) {
+        secure_reserve_exact_vec(&mut self.0, additional);
     }
-}
 
-// Random generation — only available with `rand` feature
-#[cfg(feature = "rand")]
-impl Dynamic<Vec<u8>> {
-    /// Generate fresh random bytes of the specified length using the OS RNG.
+    /// Fallible [`reserve_exact_secure`](Self::reserve_exact_secure): reports allocation
+    /// failure instead of aborting.
     ///
-    /// This is a convenience method that generates random bytes directly
-    /// without going through `DynamicRng`. Equivalent to:
-    /// `DynamicRng::generate(len).into_inner()`
+    /// On error the secret is untouched — same contents, same buffer.
     ///
     /// # Example
     ///
     /// ```
-    /// # #[cfg(feature = "rand")]
-    /// # {
     /// use secure_gate::Dynamic;
-    /// let random: Dynamic<Vec<u8>> = Dynamic::generate_random(64);
-    /// assert_eq!(random.len(), 64);
-    /// # }
+    /// let mut secret = Dynamic::<Vec<u8>>::new(b"key".to_vec());
+    /// assert!(secret.try_reserve_exact_secure(29).is_ok());
+    /// assert!(secret.try_reserve_exact_secure(usize::MAX).is_err());
+    /// assert_eq!(secret.expose_secret(), b"key");
     /// ```
     #[inline]
-    pub fn generate_random(len: usize) -> Self {
-        crate::rng::DynamicRng::generate(len).into_inner()
+    pub fn try_reserve_exact_secure(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), alloc::collections::TryReserveError> {
+        try_secure_reserve_vec(&mut self.0, additional, true)
     }
-}
 
-// Zeroize integration
-#[cfg(feature = "zeroize")]
-impl<T: ?Sized + zeroize::Zeroize> zeroize::Zeroize for Dynamic<T> {
-    fn zeroize(&mut self) {
-        self.0.zeroize();
+    /// Append `bytes`, growing through the secure path, or report allocation failure.
+    ///
+    /// Growth is amortized like `Vec::extend_from_slice`, but with `zeroize` a
+    /// reallocation copies into a fresh buffer and wipes the old one. On error nothing is
+    /// appended and the secret is untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut secret = Dynamic::<Vec<u8>>::new(b"salt:".to_vec());
+    /// secret.try_extend_secure(b"pepper")?;
+    /// assert_eq!(secret.expose_secret(), b"salt:pepper");
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    pub fn try_extend_secure(
+        &mut self,
+        bytes: &[u8],
+    ) -> Result<(), alloc::collections::TryReserveError> {
+        try_secure_reserve_vec(&mut self.0, bytes.len(), false)?;
+        self.0.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Start an empty secret with room for `capacity` bytes, or report allocation failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let buf = Dynamic::<Vec<u8>>::try_with_capacity(64)?;
+    /// assert!(buf.is_empty());
+    /// assert!(buf.expose_secret().capacity() >= 64);
+    /// assert!(Dynamic::<Vec<u8>>::try_with_capacity(usize::MAX).is_err());
+    /// # Ok::<(), std::collections::TryReserveError>(())
+    /// ```
+    #[inline]
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, alloc::collections::TryReserveError> {
+        let mut bytes = Vec::new();
+        bytes.try_reserve_exact(capacity)?;
+        Ok(Self::new(bytes))
+    }
+
+    /// A fast, **non-cryptographic** 64-bit hash of the contents, for change detection.
+    ///
+    /// Combined with an external `Mutex`, this supports "replace only if unchanged"
+    /// rotation: record the fingerprint, prepare the new value, then swap under the lock
+    /// if the fingerprint still matches. Equal contents always give equal fingerprints;
+    /// different contents almost always differ, but collisions can be forced on purpose.
+    ///
+    /// It is a hash of the secret, not a redaction: a low-entropy secret (a PIN, a
+    /// password) can be brute-forced from it offline. Keep it in memory — never log or
+    /// transmit it. This is FNV-1a, stable across versions and platforms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let a = Dynamic::<Vec<u8>>::new(b"config-v1".to_vec());
+    /// let b = Dynamic::<Vec<u8>>::new(b"config-v1".to_vec());
+    /// let c = Dynamic::<Vec<u8>>::new(b"config-v2".to_vec());
+    /// assert_eq!(a.content_fingerprint(), b.content_fingerprint());
+    /// assert_ne!(a.content_fingerprint(), c.content_fingerprint());
+    /// ```
+    #[inline]
+    pub fn content_fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        self.0
+            .iter()
+            .fold(FNV_OFFSET, |h, &b| (h ^ u64::from(b)).wrapping_mul(FNV_PRIME))
     }
 }
 
+// Capacity reclamation — only available with `zeroize` feature
 #[cfg(feature = "zeroize")]
-impl<T: ?Sized + zeroize::Zeroize> zeroize::ZeroizeOnDrop for Dynamic<T> {}
+impl Dynamic<Vec<u8>> {
+    /// Shrink the capacity to the length, wiping the old allocation first.
+    ///
+    /// `shrink_to_fit` may `realloc`, which frees the old buffer — live bytes and
+    /// spare tail alike — without wiping it. This instead copies the live bytes into a
+    /// right-sized allocation and zeroizes the *entire* old capacity before it is freed.
+    ///
+    /// Guarantee: afterwards `capacity() == len()` and no byte of the previous allocation
+    /// survives un-wiped. Cost: one allocation, one `len`-byte copy, and a wipe of the old
+    /// capacity. No-op if there is no spare capacity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut secret = Dynamic::<Vec<u8>>::new(Vec::with_capacity(64));
+    /// secret.expose_secret_mut().extend_from_slice(b"key");
+    /// secret.shrink_and_wipe();
+    /// assert_eq!(secret.expose_secret().capacity(), 3);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn shrink_and_wipe(&mut self) {
+        shrink_and_wipe_vec(&mut self.0);
+    }
+
+    /// Keep only the bytes for which `f` returns `true`, wiping the vacated tail.
+    ///
+    /// `Vec::retain` shifts kept bytes down and shortens the length, leaving copies of
+    /// the old tail in spare capacity. This compacts in place, then zeroizes
+    /// `new_len..old_len` before truncating, so nothing removed or shifted survives in
+    /// the buffer. `f` sees each byte once, in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let mut secret: Dynamic<Vec<u8>> = vec![1, 0, 2, 0, 3].into();
+    /// secret.retain_secure(|b| b != 0);
+    /// assert_eq!(secret.expose_secret(), &[1, 2, 3]);
+    /// # }
+    /// ```
+    pub fn retain_secure(&mut self, mut f: impl FnMut(u8) -> bool) {
+        let v = &mut *self.0;
+        let mut kept = 0;
+        for i in 0..v.len() {
+            let b = v[i];
+            if f(b) {
+                v[kept] = b;
+                kept += 1;
+            }
+        }
+        zeroize::Zeroize::zeroize(&mut v[kept..]);
+        v.truncate(kept);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Dynamic<String> {
+    /// Shrink the capacity to the length, wiping the old allocation first.
+    ///
+    /// Same guarantee and cost as [`Dynamic::<Vec<u8>>::shrink_and_wipe`].
+    #[inline]
+    pub fn shrink_and_wipe(&mut self) {
+        shrink_and_wipe_string(&mut self.0);
+    }
+}
+
+// === Length-prefixed wire framing ===
+
+impl Dynamic<Vec<u8>> {
+    /// Frame the secret as a 4-byte **big-endian** length followed by the bytes.
+    ///
+    /// The returned `Vec` is an unprotected copy of the secret — write it out and drop
+    /// (or wipe) it promptly.
+    ///
+    /// Panics if the secret is longer than `u32::MAX` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let secret = Dynamic::<Vec<u8>>::new(vec![0xAA, 0xBB]);
+    /// assert_eq!(secret.to_length_prefixed(), [0, 0, 0, 2, 0xAA, 0xBB]);
+    /// ```
+    pub fn to_length_prefixed(&self) -> Vec<u8> {
+        let len = u32::try_from(self.0.len()).expect("secret too long for a u32 length prefix");
+        let mut framed = Vec::with_capacity(4 + self.0.len());
+        framed.extend_from_slice(&len.to_be_bytes());
+        framed.extend_from_slice(&self.0);
+        framed
+    }
+
+    /// Decode one frame written by [`to_length_prefixed`](Self::to_length_prefixed).
+    ///
+    /// Returns the secret and the number of bytes consumed, so frames can be read back to
+    /// back. The declared length is checked against the remaining input *before* any
+    /// allocation, so a hostile prefix cannot trigger an oversized allocation. Use
+    /// [`from_length_prefixed_max`](Self::from_length_prefixed_max) to cap it further.
+    ///
+    /// # Errors
+    ///
+    /// - `"length prefix truncated"` — fewer than 4 bytes of input
+    /// - `"frame truncated"` — the declared length exceeds the remaining input
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let wire = [0, 0, 0, 2, 0xAA, 0xBB, 0, 0, 0, 0];
+    /// let (first, used) = Dynamic::<Vec<u8>>::from_length_prefixed(&wire)?;
+    /// assert_eq!((first.expose_secret().as_slice(), used), (&[0xAA, 0xBB][..], 6));
+    /// let (second, _) = Dynamic::<Vec<u8>>::from_length_prefixed(&wire[used..])?;
+    /// assert!(second.is_empty());
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    pub fn from_length_prefixed(input: &[u8]) -> Result<(Self, usize), &'static str> {
+        Self::from_length_prefixed_max(input, usize::MAX)
+    }
+
+    /// Like [`from_length_prefixed`](Self::from_length_prefixed), but also rejects any
+    /// frame declaring more than `max_len` bytes, with `"frame exceeds length limit"`.
+    pub fn from_length_prefixed_max(
+        input: &[u8],
+        max_len: usize,
+    ) -> Result<(Self, usize), &'static str> {
+        let prefix: [u8; 4] = input
+            .get(..4)
+            .and_then(|p| p.try_into().ok())
+            .ok_or("length prefix truncated")?;
+        let len = u32::from_be_bytes(prefix) as usize;
+        if len > max_len {
+            return Err("frame exceeds length limit");
+        }
+        let end = len.checked_add(4).ok_or("frame truncated")?; // 32-bit targets
+        let body = input.get(4..end).ok_or("frame truncated")?;
+        Ok((Self::new(body.to_vec()), end))
+    }
+}
+
+// === PKCS#7 padding ===
+
+impl Dynamic<Vec<u8>> {
+    /// Append PKCS#7 padding up to the next multiple of `block_size`.
+    ///
+    /// Always adds at least one byte: block-aligned input gains a full block of padding.
+    /// Growth goes through the secure path (fresh allocation + wipe under `zeroize`).
+    ///
+    /// Panics unless `1 <= block_size <= 255`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut data = Dynamic::<Vec<u8>>::new(b"YELLOW SUBMARINE".to_vec());
+    /// data.pad_pkcs7(20);
+    /// assert_eq!(&data.expose_secret()[16..], &[4, 4, 4, 4]);
+    /// ```
+    pub fn pad_pkcs7(&mut self, block_size: usize) {
+        assert!(
+            (1..=255).contains(&block_size),
+            "PKCS#7 block size must be 1..=255"
+        );
+        let pad = block_size - self.0.len() % block_size;
+        secure_reserve_vec(&mut self.0, pad);
+        self.0.resize(self.0.len() + pad, pad as u8);
+    }
+
+    /// Validate and strip PKCS#7 padding, wiping the removed bytes under `zeroize`.
+    ///
+    /// The check covers the last `min(len, 255)` bytes with the same mask arithmetic
+    /// whatever the padding length, and every failure — pad byte `0`, pad longer than the
+    /// data, or a mismatched pad byte — is reported only after the full scan, with the same
+    /// error. This is constant-time in spirit ("where feasible" is the honest claim — the
+    /// compiler is not obliged to keep the arithmetic branch-free). Unauthenticated
+    /// ciphertext plus a distinguishable unpad result is a padding oracle regardless of
+    /// timing: authenticate (MAC-then-decrypt or AEAD) before calling this.
+    ///
+    /// On error the buffer is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut data = Dynamic::<Vec<u8>>::new(vec![b'h', b'i', 2, 2]);
+    /// data.unpad_pkcs7()?;
+    /// assert_eq!(data.expose_secret(), b"hi");
+    ///
+    /// let mut bad = Dynamic::<Vec<u8>>::new(vec![b'h', 1, 2]);
+    /// assert_eq!(bad.unpad_pkcs7(), Err("invalid PKCS#7 padding"));
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    pub fn unpad_pkcs7(&mut self) -> Result<(), &'static str> {
+        let len = self.0.len();
+        let window = len.min(255);
+        let pad = u32::from(self.0.last().copied().unwrap_or(0));
+
+        // Sign bit of `a - b` (in u64) is set exactly when `a < b`.
+        let lt = |a: u64, b: u64| (a.wrapping_sub(b) >> 63) as u32;
+
+        let mut bad = lt(u64::from(pad), 1) | lt(len as u64, u64::from(pad));
+        for i in 0..window {
+            let in_pad = 0u32.wrapping_sub(lt(i as u64, u64::from(pad)));
+            let diff = u32::from(self.0[len - 1 - i]) ^ pad;
+            bad |= in_pad & diff;
+        }
+        if bad != 0 {
+            return Err("invalid PKCS#7 padding");
+        }
+
+        let keep = len - pad as usize;
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut self.0[keep..]);
+        self.0.truncate(keep);
+        Ok(())
+    }
+}
+
+// === Consuming iteration ===
+
+/// Consuming byte iterator over a `Dynamic<Vec<u8>>`.
+///
+/// Created by `Dynamic::<Vec<u8>>::into_iter`. Owns the secret buffer and, with `zeroize`,
+/// wipes the whole allocation when dropped — whether iteration finished or was
+/// abandoned early. Bytes already yielded are the caller's responsibility.
+pub struct DynamicIntoIter {
+    bytes: Vec<u8>,
+    pos: usize,
+}
+
+impl Iterator for DynamicIntoIter {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        let b = *self.bytes.get(self.pos)?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bytes.len() - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for DynamicIntoIter {}
+
+impl core::iter::FusedIterator for DynamicIntoIter {}
+
+impl core::fmt::Debug for DynamicIntoIter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for DynamicIntoIter {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bytes);
+    }
+}
+
+/// Consume the secret byte-by-byte.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::Dynamic;
+/// let secret = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+/// let sum: u32 = secret.into_iter().map(u32::from).sum();
+/// assert_eq!(sum, 6);
+/// ```
+impl IntoIterator for Dynamic<Vec<u8>> {
+    type Item = u8;
+    type IntoIter = DynamicIntoIter;
+
+    #[inline]
+    fn into_iter(self) -> DynamicIntoIter {
+        DynamicIntoIter {
+            bytes: *self.0,
+            pos: 0,
+        }
+    }
+}
+
+// === Scoped exposure ===
+
+/// Scoped mutable exposure of a `Dynamic<T>` that runs a cleanup hook when it ends.
+///
+/// Created by [`Dynamic::guard`] or [`Dynamic::guard_with`]. Derefs to `T`; when the
+/// guard is dropped the hook runs on the secret, so scratch left over from the work
+/// (e.g. spare capacity after a truncation) is wiped at scope end.
+pub struct SecretGuard<'a, T: ?Sized> {
+    inner: &'a mut T,
+    on_drop: fn(&mut T),
+}
+
+impl<T: ?Sized> core::ops::Deref for SecretGuard<'_, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.inner
+    }
+}
+
+impl<T: ?Sized> core::ops::DerefMut for SecretGuard<'_, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        self.inner
+    }
+}
+
+impl<T: ?Sized> core::fmt::Debug for SecretGuard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl<T: ?Sized> Drop for SecretGuard<'_, T> {
+    fn drop(&mut self) {
+        (self.on_drop)(self.inner);
+    }
+}
+
+impl<T: ?Sized> Dynamic<T> {
+    /// Expose the secret mutably until the returned guard is dropped, then run `on_drop`.
+    ///
+    /// Notifies the `audit` hook once, when the guard is created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut secret = Dynamic::<Vec<u8>>::new(b"scratch".to_vec());
+    /// {
+    ///     let mut g = secret.guard_with(|v| v.truncate(3));
+    ///     g.push(b'!');
+    /// } // hook runs here
+    /// assert_eq!(secret.expose_secret(), b"scr");
+    /// ```
+    #[inline]
+    pub fn guard_with(&mut self, on_drop: fn(&mut T)) -> SecretGuard<'_, T> {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
+        SecretGuard {
+            inner: &mut self.0,
+            on_drop,
+        }
+    }
+}
+
+/// Read-only exposure of a `Dynamic<T>` that flags re-entrant exposure of the same secret.
+///
+/// Created by [`Dynamic::expose_secret_guarded`] (`audit` feature). Derefs to `T`. While
+/// it is alive, a second `expose_secret_guarded` or an `expose_secret` of the same secret
+/// on the same thread trips a `debug_assert!` — nested exposure usually means a logic
+/// bug. This is a diagnostic, not a safety mechanism: release builds do not check, and
+/// exposures on other threads are not tracked. Zero-sized secrets are never tracked.
+#[cfg(feature = "audit")]
+pub struct ExposureGuard<'a, T: ?Sized> {
+    inner: &'a T,
+    addr: Option<usize>,
+}
+
+#[cfg(feature = "audit")]
+impl<T: ?Sized> core::ops::Deref for ExposureGuard<'_, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.inner
+    }
+}
+
+#[cfg(feature = "audit")]
+impl<T: ?Sized> core::fmt::Debug for ExposureGuard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "audit")]
+impl<T: ?Sized> Drop for ExposureGuard<'_, T> {
+    fn drop(&mut self) {
+        if let Some(addr) = self.addr {
+            crate::audit::exit_guard(addr);
+        }
+    }
+}
+
+#[cfg(feature = "audit")]
+impl<T: ?Sized> Dynamic<T> {
+    /// Expose the secret read-only through a guard that detects nested exposure.
+    ///
+    /// Notifies the `audit` hook like `expose_secret`. In debug builds, exposing the same
+    /// secret again on this thread while the guard lives panics; see [`ExposureGuard`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "audit")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let pw: Dynamic<String> = "hunter2".into();
+    /// {
+    ///     let g = pw.expose_secret_guarded();
+    ///     assert_eq!(g.len(), 7);
+    /// } // released here
+    /// assert_eq!(pw.expose_secret(), "hunter2");
+    /// # }
+    /// ```
+    pub fn expose_secret_guarded(&self) -> ExposureGuard<'_, T> {
+        crate::audit::on_expose::<T>();
+        let addr = self.guard_addr();
+        if let Some(addr) = addr {
+            crate::audit::enter_guard(addr);
+        }
+        ExposureGuard {
+            inner: &self.0,
+            addr,
+        }
+    }
+
+    // Heap address identifying this secret; `None` for zero-sized values, whose boxes
+    // all share one dangling address.
+    #[inline(always)]
+    fn guard_addr(&self) -> Option<usize> {
+        if core::mem::size_of_val::<T>(&self.0) == 0 {
+            return None;
+        }
+        Some(&*self.0 as *const T as *const () as usize)
+    }
+}
+
+impl Dynamic<Vec<u8>> {
+    /// Expose the secret mutably until the guard is dropped.
+    ///
+    /// With `zeroize`, dropping the guard calls `shrink_and_wipe`, so spare capacity left
+    /// in the final buffer is wiped at scope end. Growth inside the guard (`push`,
+    /// `extend`) is plain `Vec` reallocation, which frees outgrown buffers unwiped —
+    /// call [`reserve_exact_secure`](Self::reserve_exact_secure) first if the work grows
+    /// the secret. Without `zeroize` the guard is a plain scoped borrow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let mut secret = Dynamic::<Vec<u8>>::new(b"password".to_vec());
+    /// {
+    ///     let mut g = secret.guard();
+    ///     g.truncate(4);
+    /// }
+    /// assert_eq!(secret.expose_secret(), b"pass");
+    /// # #[cfg(feature = "zeroize")]
+    /// assert_eq!(secret.expose_secret().capacity(), 4);
+    /// ```
+    #[inline]
+    pub fn guard(&mut self) -> SecretGuard<'_, Vec<u8>> {
+        #[cfg(feature = "zeroize")]
+        return self.guard_with(shrink_and_wipe_vec);
+        #[cfg(not(feature = "zeroize"))]
+        return self.guard_with(|_| {});
+    }
+}
+
+impl Dynamic<String> {
+    /// Expose the secret mutably until the guard is dropped.
+    ///
+    /// Same cleanup as [`Dynamic::<Vec<u8>>::guard`]: only the final buffer is
+    /// shrink-wiped, and `push_str` inside the guard reallocates without wiping.
+    #[inline]
+    pub fn guard(&mut self) -> SecretGuard<'_, String> {
+        #[cfg(feature = "zeroize")]
+        return self.guard_with(shrink_and_wipe_string);
+        #[cfg(not(feature = "zeroize"))]
+        return self.guard_with(|_| {});
+    }
+}
+
+// === Secure growth ===
+//
+// `String::reserve` / `Vec::reserve_exact` may `realloc`, which frees the old buffer
+// without wiping it. Under `zeroize`, growth instead copies into a fresh allocation
+// and wipes the old one.
+
+#[inline]
+fn grown_capacity(len: usize, capacity: usize, additional: usize) -> usize {
+    let required = len.checked_add(additional).expect("capacity overflow");
+    required.max(capacity.saturating_mul(2))
+}
+
+#[inline]
+fn secure_reserve_exact_vec(v: &mut Vec<u8>, additional: usize) {
+    if v.capacity() - v.len() >= additional {
+        return;
+    }
+    #[cfg(feature = "zeroize")]
+    {
+        let required = v.len().checked_add(additional).expect("capacity overflow");
+        let mut fresh = Vec::with_capacity(required);
+        fresh.extend_from_slice(v);
+        zeroize::Zeroize::zeroize(v);
+        *v = fresh;
+    }
+    #[cfg(not(feature = "zeroize"))]
+    v.reserve_exact(additional);
+}
+
+#[inline]
+pub(crate) fn secure_reserve_vec(v: &mut Vec<u8>, additional: usize) {
+    if v.capacity() - v.len() >= additional {
+        return;
+    }
+    #[cfg(feature = "zeroize")]
+    {
+        let mut fresh = Vec::with_capacity(grown_capacity(v.len(), v.capacity(), additional));
+        fresh.extend_from_slice(v);
+        zeroize::Zeroize::zeroize(v);
+        *v = fresh;
+    }
+    #[cfg(not(feature = "zeroize"))]
+    v.reserve(grown_capacity(v.len(), v.capacity(), additional) - v.len());
+}
+
+// Fallible counterpart of `secure_reserve_vec` (amortized) / `secure_reserve_exact_vec`.
+// An overflowing length saturates, so the allocator reports `CapacityOverflow`.
+fn try_secure_reserve_vec(
+    v: &mut Vec<u8>,
+    additional: usize,
+    exact: bool,
+) -> Result<(), alloc::collections::TryReserveError> {
+    if v.capacity() - v.len() >= additional {
+        return Ok(());
+    }
+    let required = v.len().saturating_add(additional);
+    let target = if exact {
+        required
+    } else {
+        required.max(v.capacity().saturating_mul(2))
+    };
+    #[cfg(feature = "zeroize")]
+    {
+        let mut fresh = Vec::new();
+        fresh.try_reserve_exact(target)?;
+        fresh.extend_from_slice(v);
+        zeroize::Zeroize::zeroize(v);
+        *v = fresh;
+        Ok(())
+    }
+    #[cfg(not(feature = "zeroize"))]
+    v.try_reserve_exact(target - v.len())
+}
+
+#[inline]
+pub(crate) fn secure_reserve_string(s: &mut String, additional: usize) {
+    if s.capacity() - s.len() >= additional {
+        return;
+    }
+    #[cfg(feature = "zeroize")]
+    {
+        let mut fresh = String::with_capacity(grown_capacity(s.len(), s.capacity(), additional));
+        fresh.push_str(s);
+        zeroize::Zeroize::zeroize(s);
+        *s = fresh;
+    }
+    #[cfg(not(feature = "zeroize"))]
+    s.reserve(grown_capacity(s.len(), s.capacity(), additional) - s.len());
+}
+
+#[cfg(feature = "zeroize")]
+#[inline]
+pub(crate) fn shrink_and_wipe_vec(v: &mut Vec<u8>) {
+    if v.capacity() == v.len() {
+        return;
+    }
+    let fresh = v.as_slice().to_vec();
+    zeroize::Zeroize::zeroize(v);
+    *v = fresh;
+}
+
+#[cfg(feature = "zeroize")]
+#[inline]
+pub(crate) fn shrink_and_wipe_string(s: &mut String) {
+    if s.capacity() == s.len() {
+        return;
+    }
+    let fresh = String::from(s.as_str());
+    zeroize::Zeroize::zeroize(s);
+    *s = fresh;
+}
+
+/// Assemble a secret string with `write!` / `writeln!`.
+///
+/// With `zeroize`, every growth moves into a fresh allocation and wipes the old one,
+/// so no stale fragments are left behind in freed memory.
+///
+/// # Example
+///
+/// ```
+/// use core::fmt::Write;
+/// use secure_gate::Dynamic;
+/// let mut secret = Dynamic::<String>::new(String::new());
+/// write!(secret, "{}:{}", "user", 42).unwrap();
+/// assert_eq!(secret.expose_secret(), "user:42");
+/// ```
+impl core::fmt::Write for Dynamic<String> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        secure_reserve_string(&mut self.0, s.len());
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+/// Collect bytes straight into a secret buffer.
+///
+/// The buffer is sized from the iterator's lower `size_hint`. With `zeroize`, any growth
+/// beyond that moves into a fresh allocation and wipes the old one, so a reallocation
+/// mid-collection leaves no stale copy behind.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::Dynamic;
+/// let secret: Dynamic<Vec<u8>> = (1u8..=4).collect();
+/// assert_eq!(secret.expose_secret(), &[1, 2, 3, 4]);
+/// ```
+impl core::iter::FromIterator<u8> for Dynamic<Vec<u8>> {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut bytes = Vec::with_capacity(iter.size_hint().0);
+        for b in iter {
+            secure_reserve_vec(&mut bytes, 1);
+            bytes.push(b);
+        }
+        Self::new(bytes)
+    }
+}
+
+// === Growth-audited buffer ===
+
+/// An append-only secret byte buffer that accounts for every allocation it retires.
+///
+/// A `Vec` that grows via `realloc` frees its old buffer with the secret still in it.
+/// Here every growth goes through the secure path instead: with `zeroize`, the bytes are
+/// copied into a fresh allocation and the old one is zeroized **at the moment of
+/// growth**, before it is freed. No pointer to a freed buffer is ever kept — and none
+/// needs to be, since nothing is left to wipe later. On drop, the current buffer is
+/// wiped as well.
+///
+/// [`retired_allocations`](Self::retired_allocations) counts the buffers replaced so far,
+/// so tests and audits can check how often a secret moved. Without `zeroize` the count
+/// is kept but retired buffers are not wiped. Reads go through
+/// [`expose_secret`](Self::expose_secret), which notifies the [`audit`](crate::audit) hook.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "audit")]
+/// # {
+/// use secure_gate::AuditedDynamicVec;
+/// let mut buf = AuditedDynamicVec::with_capacity(4);
+/// buf.extend_from_slice(b"key:");
+/// assert_eq!(buf.retired_allocations(), 0);
+/// buf.extend_from_slice(b"more material");
+/// assert_eq!(buf.retired_allocations(), 1);
+/// assert_eq!(buf.expose_secret(), b"key:more material");
+/// # }
+/// ```
+#[cfg(feature = "audit")]
+pub struct AuditedDynamicVec {
+    buf: Vec<u8>,
+    retired: usize,
+}
+
+#[cfg(feature = "audit")]
+impl AuditedDynamicVec {
+    /// Start an empty buffer. Does not allocate.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            retired: 0,
+        }
+    }
+
+    /// Start with room for `capacity` bytes, so no allocation is retired up to that size.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+            retired: 0,
+        }
+    }
+
+    /// Append one byte.
+    #[inline]
+    pub fn push(&mut self, byte: u8) {
+        self.reserve(1);
+        self.buf.push(byte);
+    }
+
+    /// Append a slice of bytes.
+    #[inline]
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Number of previous allocations this buffer has moved out of (and, with
+    /// `zeroize`, wiped) — safe public metadata.
+    #[inline(always)]
+    pub const fn retired_allocations(&self) -> usize {
+        self.retired
+    }
+
+    /// Number of bytes held.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if the buffer holds no bytes.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Current allocation size in bytes.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    /// Expose the bytes for read-only access. Notifies the `audit` hook.
+    #[inline]
+    pub fn expose_secret(&self) -> &[u8] {
+        crate::audit::on_expose::<Vec<u8>>();
+        &self.buf
+    }
+
+    /// Move the bytes into a [`Dynamic<Vec<u8>>`] without copying.
+    #[inline]
+    pub fn into_dynamic(mut self) -> Dynamic<Vec<u8>> {
+        Dynamic::new(core::mem::take(&mut self.buf))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        if self.buf.capacity() - self.buf.len() >= additional {
+            return;
+        }
+        if self.buf.capacity() != 0 {
+            self.retired += 1;
+        }
+        secure_reserve_vec(&mut self.buf, additional);
+    }
+}
+
+#[cfg(feature = "audit")]
+impl Default for AuditedDynamicVec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "audit")]
+impl core::fmt::Debug for AuditedDynamicVec {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(all(feature = "audit", feature = "zeroize"))]
+impl Drop for AuditedDynamicVec {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.buf);
+    }
+}
+
+// === Type-erased secrets ===
+
+impl Dynamic<dyn core::any::Any> {
+    /// Recover the concrete type of a type-erased secret, like `Box::downcast`.
+    ///
+    /// The box moves across unchanged — no copy, and the secret stays wrapped on both
+    /// the success and the error path. Build the erased value with
+    /// `Dynamic::<dyn Any>::new_boxed(Box::new(value))`.
+    ///
+    /// `dyn Any` is not `Zeroize`, so an erased secret cannot be wiped in place: downcast
+    /// it back first (or wipe before erasing).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::any::Any;
+    /// use secure_gate::Dynamic;
+    /// let erased: Dynamic<dyn Any> = Dynamic::new_boxed(Box::new(String::from("token")));
+    /// assert!(erased.is::<String>());
+    ///
+    /// let erased = erased.downcast::<Vec<u8>>().unwrap_err(); // wrong type: handed back
+    /// let token: Dynamic<String> = erased.downcast().unwrap();
+    /// assert_eq!(token.expose_secret(), "token");
+    /// ```
+    #[inline]
+    pub fn downcast<T: core::any::Any>(self) -> Result<Dynamic<T>, Self> {
+        self.0.downcast::<T>().map(Dynamic).map_err(Dynamic)
+    }
+
+    /// Returns `true` if the erased secret is a `T` — safe public metadata.
+    #[inline]
+    pub fn is<T: core::any::Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+}
+
+impl Dynamic<dyn core::any::Any + Send> {
+    /// Recover the concrete type of a `Send` type-erased secret; same as the `dyn Any`
+    /// version.
+    #[inline]
+    pub fn downcast<T: core::any::Any>(self) -> Result<Dynamic<T>, Self> {
+        self.0.downcast::<T>().map(Dynamic).map_err(Dynamic)
+    }
+
+    /// Returns `true` if the erased secret is a `T` — safe public metadata.
+    #[inline]
+    pub fn is<T: core::any::Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+}
+
+// === Cow construction ===
+
+impl Dynamic<String> {
+    /// Take ownership of a possibly-borrowed string.
+    ///
+    /// An owned `Cow` moves its buffer in without copying; a borrowed one is copied once,
+    /// straight into the protected wrapper — no unprotected `into_owned()` intermediate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// use std::borrow::Cow;
+    /// let borrowed = Dynamic::<String>::from_cow(Cow::Borrowed("hunter2"));
+    /// let owned = Dynamic::<String>::from_cow(Cow::Owned("hunter2".to_string()));
+    /// assert_eq!(borrowed.expose_secret(), owned.expose_secret());
+    /// ```
+    #[inline]
+    pub fn from_cow(cow: alloc::borrow::Cow<'_, str>) -> Self {
+        Self::new(cow.into_owned())
+    }
+}
+
+impl Dynamic<Vec<u8>> {
+    /// Take ownership of possibly-borrowed bytes.
+    ///
+    /// An owned `Cow` moves its buffer in without copying; a borrowed one is copied once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// use std::borrow::Cow;
+    /// let key = Dynamic::<Vec<u8>>::from_cow(Cow::Borrowed(&[1u8, 2, 3][..]));
+    /// assert_eq!(key.expose_secret(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn from_cow(cow: alloc::borrow::Cow<'_, [u8]>) -> Self {
+        Self::new(cow.into_owned())
+    }
+}
+
+// === Convenient From impls ===
+impl<T> From<T> for Dynamic<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        Self(Box::new(value))
+    }
+}
+
+impl<T: ?Sized> From<Box<T>> for Dynamic<T> {
+    #[inline(always)]
+    fn from(boxed: Box<T>) -> Self {
+        Self(boxed)
+    }
+}
+
+impl From<&str> for Dynamic<String> {
+    #[inline(always)]
+    fn from(s: &str) -> Self {
+        Self(Box::new(s.to_string()))
+    }
+}
+
+impl From<Dynamic<String>> for Dynamic<Vec<u8>> {
+    /// Reinterpret a secret string as its UTF-8 bytes.
+    ///
+    /// The string's buffer is moved, not copied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let pw: Dynamic<String> = "hunter2".into();
+    /// let bytes: Dynamic<Vec<u8>> = pw.into();
+    /// assert_eq!(bytes.expose_secret(), b"hunter2");
+    /// ```
+    #[inline]
+    fn from(secret: Dynamic<String>) -> Self {
+        Dynamic(Box::new(secret.0.into_bytes()))
+    }
+}
+
+impl TryFrom<Dynamic<Vec<u8>>> for Dynamic<String> {
+    /// The original bytes, handed back untouched (and still wrapped) on invalid UTF-8.
+    type Error = Dynamic<Vec<u8>>;
+
+    /// Reinterpret secret bytes as a string if they are valid UTF-8.
+    ///
+    /// The buffer is moved, not copied — on success and on failure alike.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Dynamic;
+    /// let bytes = Dynamic::<Vec<u8>>::new(b"hunter2".to_vec());
+    /// let pw = Dynamic::<String>::try_from(bytes).unwrap();
+    /// assert_eq!(pw.expose_secret(), "hunter2");
+    ///
+    /// let bad = Dynamic::<Vec<u8>>::new(vec![0xFF, 0xFE]);
+    /// let back = Dynamic::<String>::try_from(bad).unwrap_err();
+    /// assert_eq!(back.expose_secret(), &[0xFF, 0xFE]);
+    /// ```
+    #[inline]
+    fn try_from(secret: Dynamic<Vec<u8>>) -> Result<Self, Self::Error> {
+        match String::from_utf8(*secret.0) {
+            Ok(s) => Ok(Dynamic(Box::new(s))),
+            Err(e) => Err(Dynamic(Box::new(e.into_bytes()))),
+        }
+    }
+}
+
+// Constant-time equality — only available with `conversions` feature
+#[cfg(feature = "conversions")]
+impl<T> Dynamic<T>
+where
+    T: ?Sized + AsRef<[u8]>,
+{
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        use crate::conversions::SecureConversionsExt;
+        self.expose_secret()
+            .as_ref()
+            .ct_eq(other.expose_secret().as_ref())
+    }
+}
+
+#[cfg(feature = "conversions")]
+impl Dynamic<String> {
+    /// Constant-time comparison against a candidate string (e.g. a submitted password).
+    ///
+    /// Runs in time independent of *where* the contents differ. A length mismatch
+    /// returns `false` immediately, so the candidate's length relative to the secret
+    /// may still leak — pad or hash both sides first if length itself is sensitive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let stored: Dynamic<String> = "hunter2".into();
+    /// assert!(stored.ct_eq_str("hunter2"));
+    /// assert!(!stored.ct_eq_str("hunter3"));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn ct_eq_str(&self, candidate: &str) -> bool {
+        use crate::conversions::SecureConversionsExt;
+        self.0.as_bytes().ct_eq(candidate.as_bytes())
+    }
+
+    /// [`ct_eq_str`](Self::ct_eq_str), ignoring ASCII case — for tokens that some systems
+    /// normalize.
+    ///
+    /// Both sides are lowercased byte by byte with branchless arithmetic (no lookups,
+    /// no per-character branches), so timing does not reveal which characters were
+    /// uppercase, and the lowercased bytes are compared in constant time. Non-ASCII bytes
+    /// must match exactly. The length caveat of `ct_eq_str` applies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let stored: Dynamic<String> = "AbC-123".into();
+    /// assert!(stored.ct_eq_ignore_ascii_case("abc-123"));
+    /// assert!(!stored.ct_eq_ignore_ascii_case("abd-123"));
+    /// # }
+    /// ```
+    pub fn ct_eq_ignore_ascii_case(&self, other: &str) -> bool {
+        use subtle::ConstantTimeEq;
+
+        // 0x20 if `b` is in b'A'..=b'Z', else 0: `b - 'A'` wraps below 26 only for
+        // uppercase, and the borrow out of `x - 26` becomes the mask.
+        #[inline(always)]
+        fn ct_to_lower(b: u8) -> u8 {
+            let x = u16::from(b.wrapping_sub(b'A'));
+            let upper_mask = (x.wrapping_sub(26) >> 8) as u8;
+            b | (upper_mask & 0x20)
+        }
+
+        let (a, b) = (self.0.as_bytes(), other.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut diff = 0u8;
+        for (&x, &y) in a.iter().zip(b) {
+            diff |= ct_to_lower(x) ^ ct_to_lower(y);
+        }
+        diff.ct_eq(&0).into()
+    }
+}
+
+#[cfg(feature = "conversions")]
+impl Dynamic<Vec<u8>> {
+    /// Constant-time comparison that hides the lengths as well as the contents.
+    ///
+    /// [`ct_eq`](Self::ct_eq) returns as soon as the lengths differ, which tells a timing
+    /// observer whether a guess has the right length. This method instead walks exactly
+    /// `pad_to` byte positions for every call, treating bytes past either buffer's end as
+    /// zero, and folds in a constant-time length comparison — so `[1, 0]` and `[1]` are
+    /// still unequal. Timing then depends only on `pad_to`, not on either length or on
+    /// where the buffers differ.
+    ///
+    /// # The `pad_to` contract
+    ///
+    /// `pad_to` should be a **public** upper bound on the secret's length (e.g. the
+    /// maximum size of the format). If either buffer is longer than `pad_to`, this
+    /// returns `false` immediately, which reveals that the bound was exceeded — but
+    /// nothing more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let stored: Dynamic<Vec<u8>> = vec![1, 2, 3].into();
+    /// assert!(stored.ct_eq_padded(&vec![1, 2, 3].into(), 64));
+    /// assert!(!stored.ct_eq_padded(&vec![1, 2].into(), 64));
+    /// assert!(!stored.ct_eq_padded(&vec![1, 2, 3, 0].into(), 64));
+    /// # }
+    /// ```
+    pub fn ct_eq_padded(&self, other: &Self, pad_to: usize) -> bool {
+        use subtle::{ConditionallySelectable, ConstantTimeEq, ConstantTimeLess};
+
+        // Every position reads an in-bounds byte (or nothing, for an empty buffer) and
+        // masks it to zero past the end, so the work per position is the same.
+        fn padded(bytes: &[u8], i: usize) -> u8 {
+            let raw = bytes
+                .get(i.min(bytes.len().saturating_sub(1)))
+                .copied()
+                .unwrap_or(0);
+            let in_range = (i as u64).ct_lt(&(bytes.len() as u64));
+            u8::conditional_select(&0, &raw, in_range)
+        }
+
+        let (a, b) = (self.0.as_slice(), other.0.as_slice());
+        if a.len() > pad_to || b.len() > pad_to {
+            return false;
+        }
+        let mut eq = (a.len() as u64).ct_eq(&(b.len() as u64));
+        for i in 0..pad_to {
+            eq &= padded(a, i).ct_eq(&padded(b, i));
+        }
+        eq.into()
+    }
+}
+
+// Random generation — only available with `rand` feature
+#[cfg(feature = "rand")]
+impl Dynamic<Vec<u8>> {
+    /// Generate fresh random bytes of the specified length using the OS RNG.
+    ///
+    /// This is a convenience method that generates random bytes directly
+    /// without going through `DynamicRng`. Equivalent to:
+    /// `DynamicRng::generate(len).into_inner()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let random: Dynamic<Vec<u8>> = Dynamic::generate_random(64);
+    /// assert_eq!(random.len(), 64);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate_random(len: usize) -> Self {
+        crate::rng::DynamicRng::generate(len).into_inner()
+    }
+}
+
+// Zeroize integration
+/// A direct impl (not via `Deref`), so `#[derive(Zeroize)]` on a struct with
+/// `Dynamic<T>` fields works as-is.
+///
+/// ```
+/// # #[cfg(feature = "zeroize")]
+/// # {
+/// use secure_gate::Dynamic;
+/// use zeroize::Zeroize;
+///
+/// #[derive(Zeroize)]
+/// struct Credentials {
+///     user: String,
+///     password: Dynamic<String>,
+/// }
+///
+/// let mut c = Credentials { user: "alice".into(), password: "hunter2".into() };
+/// c.zeroize();
+/// assert!(c.password.is_empty());
+/// # }
+/// ```
+#[cfg(feature = "zeroize")]
+impl<T: ?Sized + zeroize::Zeroize> zeroize::Zeroize for Dynamic<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+// Only when `T` wipes itself — `Dynamic` has no `Drop` (see `Fixed`)
+#[cfg(feature = "zeroize")]
+impl<T: ?Sized + zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Dynamic<T> {}
+
+// `zeroize::Zeroizing` bridges — for incremental migration in either direction
+
+/// Move the value out of a `Zeroizing` wrapper.
+///
+/// `Zeroizing` wipes on drop, so the value is taken with `mem::take` (leaving
+/// `T::default()` behind for it to wipe). For `Vec` / `String` this moves the heap
+/// buffer itself — nothing is copied.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "zeroize")]
+/// # {
+/// use secure_gate::Dynamic;
+/// let legacy = zeroize::Zeroizing::new(vec![1u8, 2, 3]);
+/// let secret: Dynamic<Vec<u8>> = legacy.into();
+/// assert_eq!(secret.expose_secret(), &[1, 2, 3]);
+/// # }
+/// ```
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize + Default> From<zeroize::Zeroizing<T>> for Dynamic<T> {
+    fn from(mut value: zeroize::Zeroizing<T>) -> Self {
+        Self::new(core::mem::take(&mut *value))
+    }
+}
+
+/// Move the value out of the box into a `Zeroizing` wrapper.
+///
+/// For heap-owning `T` (`Vec`, `String`) the buffer moves as-is; only its
+/// pointer/length/capacity header is left in the freed box. Fixed-size `T` is copied
+/// out of the box, which is then freed without a wipe. Notifies the `audit` hook, since
+/// the value leaves the gate.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "zeroize")]
+/// # {
+/// use secure_gate::Dynamic;
+/// let secret: Dynamic<Vec<u8>> = vec![4u8, 5, 6].into();
+/// let legacy: zeroize::Zeroizing<Vec<u8>> = secret.into();
+/// assert_eq!(*legacy, [4, 5, 6]);
+/// # }
+/// ```
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> From<Dynamic<T>> for zeroize::Zeroizing<T> {
+    fn from(value: Dynamic<T>) -> Self {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
+        zeroize::Zeroizing::new(*value.0)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> Dynamic<T> {
+    /// Unwrap into a [`zeroize::Zeroizing`], so the extracted value still wipes when it is
+    /// eventually dropped.
+    ///
+    /// The method form of `Zeroizing::from(dynamic)` — prefer it over
+    /// [`disarm`](Self::disarm) whenever the value must leave the wrapper, since a raw
+    /// `Box<T>` dropped normally is never wiped. Same move semantics and `audit`
+    /// notification as the `From` impl.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let secret: Dynamic<Vec<u8>> = vec![1u8, 2, 3].into();
+    /// let inner = secret.into_inner_zeroizing();
+    /// assert_eq!(*inner, [1, 2, 3]);
+    /// // `inner` is wiped here
+    /// # }
+    /// ```
+    #[inline]
+    pub fn into_inner_zeroizing(self) -> zeroize::Zeroizing<T> {
+        self.into()
+    }
+}
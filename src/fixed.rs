@@ -33,15 +33,28 @@ use core::fmt;
 /// assert_eq!(key.expose_secret()[0], 0x42);
 /// ```
 ///
-/// With `zeroize` feature (automatic wipe on drop):
+/// With `zeroize` feature (explicit or scope-bound wipe):
 /// ```
 /// # #[cfg(feature = "zeroize")]
 /// # {
 /// use secure_gate::Fixed;
 /// let mut secret = Fixed::new([1u8, 2, 3]);
-/// drop(secret); // memory wiped automatically
+/// secret.zeroize_now(); // wiped in place
+///
+/// let scoped = Fixed::new([4u8; 32]).into_zeroizing();
+/// drop(scoped); // wiped by `Zeroizing`'s drop
 /// # }
 /// ```
+///
+/// `Fixed` has no `Drop` of its own (see the zeroize notes at the bottom of this file),
+/// so a plain `[u8; N]` is wiped on drop only through `into_zeroizing`; inner types
+/// that derive `ZeroizeOnDrop` wipe themselves. Accordingly, `Fixed<T>` is
+/// `ZeroizeOnDrop` only when `T` is:
+///
+/// ```compile_fail
+/// fn assert_zod<T: zeroize::ZeroizeOnDrop>() {}
+/// assert_zod::<secure_gate::Fixed<[u8; 32]>>(); // not wiped on drop
+/// ```
 pub struct Fixed<T>(T); // ← field is PRIVATE
 
 impl<T> Fixed<T> {
@@ -60,6 +73,15 @@ impl<T> Fixed<T> {
         Fixed(value)
     }
 
+    /// Validate `value`, then wrap it.
+    ///
+    /// With `zeroize`, this requires `T: Zeroize` and wipes a rejected value.
+    #[cfg(not(feature = "zeroize"))]
+    pub fn try_new<E>(value: T, validate: impl FnOnce(&T) -> Result<(), E>) -> Result<Self, E> {
+        validate(&value)?;
+        Ok(Fixed(value))
+    }
+
     /// Expose the inner value for read-only access.
     ///
     /// This is the **only** way to read the secret — loud and auditable.
@@ -117,6 +139,8 @@ impl<T> Fixed<T> {
 impl<T: zeroize::Zeroize> Fixed<T> {
     /// Explicitly zeroize the secret immediately.
     ///
+    /// Works for any `T: Zeroize`, including structs that `#[derive(Zeroize)]`.
+    ///
     /// This is useful when you want to wipe memory before the value goes out of scope,
     /// or when you want to make the zeroization intent explicit in the code.
     ///
@@ -135,11 +159,109 @@ impl<T: zeroize::Zeroize> Fixed<T> {
     pub fn zeroize_now(&mut self) {
         self.0.zeroize();
     }
+
+    /// Move the secret into a [`zeroize::Zeroizing`] wrapper, for `zeroize`-native APIs.
+    ///
+    /// The value is moved, not copied; the `Zeroizing` wipes it when dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([7u8; 32]);
+    /// let key: zeroize::Zeroizing<[u8; 32]> = key.into_zeroizing();
+    /// assert_eq!(*key, [7u8; 32]);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn into_zeroizing(self) -> zeroize::Zeroizing<T> {
+        zeroize::Zeroizing::new(self.0)
+    }
+
+    /// Validate `value`, then wrap it — wiping it if validation fails.
+    ///
+    /// Centralizes "validate then wrap" for inner types with invariants (e.g. a key that
+    /// must be a valid scalar). On `Err`, the rejected value is zeroized before it is
+    /// dropped, so invalid secret material never lingers. Without `zeroize`, the same
+    /// method exists for any `T` and simply drops the rejected value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let not_all_zero = |k: &[u8; 32]| {
+    ///     if k.iter().all(|&b| b == 0) { Err("all-zero key") } else { Ok(()) }
+    /// };
+    /// assert!(Fixed::try_new([1u8; 32], not_all_zero).is_ok());
+    /// assert_eq!(Fixed::try_new([0u8; 32], not_all_zero).unwrap_err(), "all-zero key");
+    /// # }
+    /// ```
+    pub fn try_new<E>(mut value: T, validate: impl FnOnce(&T) -> Result<(), E>) -> Result<Self, E> {
+        match validate(&value) {
+            Ok(()) => Ok(Fixed(value)),
+            Err(e) => {
+                value.zeroize();
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Fixed<[u8; N]> {
+    /// Copy the bytes out, then zeroize `self` in place before returning the copy.
+    ///
+    /// Moving a `Fixed` out by value can leave a bitwise copy in the old stack slot until
+    /// it is overwritten. Here the wrapper's own storage is guaranteed wiped; the
+    /// returned array is the caller's responsibility (wrap it again, or wipe it when
+    /// done). `self` stays usable and reads as all zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let mut key = Fixed::new([0xAB; 16]);
+    /// let bytes = key.take_and_wipe();
+    /// assert_eq!(bytes, [0xAB; 16]);
+    /// assert_eq!(key.expose_secret(), &[0u8; 16]);
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use = "the returned bytes are the secret; dropping them unused discards it"]
+    pub fn take_and_wipe(&mut self) -> [u8; N] {
+        let out = self.0;
+        zeroize::Zeroize::zeroize(&mut self.0);
+        out
+    }
 }
 
 // === Byte-array specific helpers ===
 
 impl<const N: usize> Fixed<[u8; N]> {
+    /// Wrap a byte array — the `const` counterpart of `From<[u8; N]>`.
+    ///
+    /// Trait impls cannot be called in `const` contexts, so use this (or [`Fixed::new`])
+    /// to build `static` / `const` keys and test vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// static TEST_KEY: Fixed<[u8; 4]> = Fixed::from_array([0xAB; 4]);
+    /// const KEY_LEN: usize = TEST_KEY.len();
+    /// assert_eq!(KEY_LEN, 4);
+    /// ```
+    #[inline(always)]
+    pub const fn from_array(arr: [u8; N]) -> Self {
+        Self::new(arr)
+    }
+
     /// Returns the fixed length in bytes.
     ///
     /// This is safe public metadata — does not expose the secret.
@@ -156,6 +278,70 @@ impl<const N: usize> Fixed<[u8; N]> {
         N == 0
     }
 
+    /// Expose the secret as a `&[u8]` slice — for APIs that take slices.
+    ///
+    /// Shorthand for `expose_secret().as_slice()`, named so the exposure stays obvious.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([7u8; 4]);
+    /// let slice: &[u8] = key.expose_slice();
+    /// assert_eq!(slice, &[7, 7, 7, 7]);
+    /// ```
+    #[inline(always)]
+    pub const fn expose_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Expose the secret as a `&mut [u8]` slice — e.g. an output buffer for a KDF.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut key = Fixed::new([0u8; 4]);
+    /// key.expose_slice_mut().copy_from_slice(&[1, 2, 3, 4]);
+    /// assert_eq!(key.expose_secret(), &[1, 2, 3, 4]);
+    /// ```
+    #[inline(always)]
+    pub fn expose_slice_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+
+    /// Borrow the `LEN`-byte sub-array starting at `OFF` — typed access to a named region
+    /// of the secret (e.g. the MAC half of an `enc || mac` key), without copying.
+    ///
+    /// `OFF + LEN <= N` is checked at compile time:
+    ///
+    /// ```compile_fail
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([0u8; 32]);
+    /// let _ = key.sub::<16, 17>(); // 16 + 17 > 32
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new(core::array::from_fn::<u8, 32, _>(|i| i as u8));
+    /// let mac_key: &[u8; 16] = key.sub::<16, 16>();
+    /// assert_eq!(mac_key[0], 16);
+    /// ```
+    #[inline]
+    pub fn sub<const OFF: usize, const LEN: usize>(&self) -> &[u8; LEN] {
+        const {
+            assert!(
+                OFF <= N && LEN <= N - OFF,
+                "Fixed::sub range out of bounds"
+            )
+        };
+        self.0[OFF..]
+            .first_chunk::<LEN>()
+            .expect("bounds checked at compile time")
+    }
+
     /// Create from a byte slice of exactly `N` bytes.
     ///
     /// Panics if the slice length does not match `N`.
@@ -175,6 +361,269 @@ impl<const N: usize> Fixed<[u8; N]> {
         arr.copy_from_slice(&bytes[..N]);
         Self::new(arr)
     }
+
+    /// Copy the secret bytes into `dst` at `offset`, returning the offset just past them.
+    ///
+    /// No allocation — intended for assembling `header || key || ...` blobs on the stack
+    /// in `no_std` code by chaining calls.
+    ///
+    /// Panics if `offset + N > dst.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([0xAAu8; 4]);
+    /// let mut blob = [0u8; 6];
+    /// blob[0] = 0x01; // header byte
+    /// let end = key.write_into(&mut blob, 1);
+    /// assert_eq!(end, 5);
+    /// assert_eq!(blob, [0x01, 0xAA, 0xAA, 0xAA, 0xAA, 0x00]);
+    /// ```
+    #[inline]
+    pub fn write_into(&self, dst: &mut [u8], offset: usize) -> usize {
+        let end = offset
+            .checked_add(N)
+            .filter(|&end| end <= dst.len())
+            .expect("destination buffer too small");
+        dst[offset..end].copy_from_slice(&self.0);
+        end
+    }
+
+    /// Overwrite every byte with `byte` — e.g. a `0xFF` poison pattern before reuse.
+    ///
+    /// For wiping, prefer `zeroize_now`, which the compiler cannot optimize away.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut key = Fixed::new([1u8; 4]);
+    /// key.fill(0xFF);
+    /// assert_eq!(key.expose_secret(), &[0xFF; 4]);
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, byte: u8) {
+        self.0.fill(byte);
+    }
+
+    /// Apply `f` to every byte, returning the result as a new fixed secret.
+    ///
+    /// The source is never handed out as a bare slice. Timing is whatever `f`'s timing
+    /// is: if the mapping runs on secret bytes, keep `f` constant-time (no branches or
+    /// table lookups indexed by the byte).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([0x0Fu8, 0xF0]);
+    /// let complement = key.map_bytes(|b| !b);
+    /// assert_eq!(complement.expose_secret(), &[0xF0, 0x0F]);
+    /// ```
+    #[inline]
+    pub fn map_bytes(&self, f: impl Fn(u8) -> u8) -> Self {
+        Self::new(core::array::from_fn(|i| f(self.0[i])))
+    }
+
+    /// Add one, treating the bytes as a big-endian integer — e.g. a CTR/GCM counter nonce.
+    ///
+    /// **Wraps** from all-`0xFF` to all-zero. For a nonce that means reuse, which is
+    /// catastrophic for CTR/GCM; use [`checked_increment_be`](Self::checked_increment_be)
+    /// unless wraparound is provably unreachable. The carry loop touches every byte, with
+    /// no early exit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut ctr = Fixed::new([0x00, 0x00, 0xFF]);
+    /// ctr.increment_be();
+    /// assert_eq!(ctr.expose_secret(), &[0x00, 0x01, 0x00]);
+    ///
+    /// let mut max = Fixed::new([0xFF; 2]);
+    /// max.increment_be();
+    /// assert_eq!(max.expose_secret(), &[0x00; 2]); // wrapped
+    /// ```
+    #[inline]
+    pub fn increment_be(&mut self) {
+        add_one(self.0.iter_mut().rev());
+    }
+
+    /// Add one, treating the bytes as a little-endian integer.
+    ///
+    /// **Wraps** from all-`0xFF` to all-zero, with the same nonce-reuse caveat as
+    /// [`increment_be`](Self::increment_be).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut ctr = Fixed::new([0xFF, 0x00, 0x00]);
+    /// ctr.increment_le();
+    /// assert_eq!(ctr.expose_secret(), &[0x00, 0x01, 0x00]);
+    /// ```
+    #[inline]
+    pub fn increment_le(&mut self) {
+        add_one(self.0.iter_mut());
+    }
+
+    /// Add one (big-endian), refusing to wrap.
+    ///
+    /// Returns `true` if the value was incremented. Returns `false` — leaving the value at
+    /// all-`0xFF` — if the counter is exhausted; the caller must then stop using the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut ctr = Fixed::new([0x00, 0xFE]);
+    /// assert!(ctr.checked_increment_be());
+    /// assert_eq!(ctr.expose_secret(), &[0x00, 0xFF]);
+    ///
+    /// let mut max = Fixed::new([0xFF; 2]);
+    /// assert!(!max.checked_increment_be());
+    /// assert_eq!(max.expose_secret(), &[0xFF; 2]); // unchanged
+    /// ```
+    #[inline]
+    #[must_use = "a `false` return means the counter is exhausted"]
+    pub fn checked_increment_be(&mut self) -> bool {
+        if add_one(self.0.iter_mut().rev()) {
+            self.0.fill(0xFF);
+            return false;
+        }
+        true
+    }
+
+    /// Add `addend`, treating the bytes as a big-endian integer — e.g. to skip a CTR/GCM
+    /// counter ahead by a block range for parallel encryption.
+    ///
+    /// Returns `true` on **overflow**: the sum does not fit in `N` bytes. The value is then
+    /// left unchanged rather than wrapped, so an exhausted counter cannot silently restart
+    /// at a reused nonce. The carry propagates through every byte, with no early exit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut ctr = Fixed::new([0x00, 0x00, 0xFF, 0xFF]);
+    /// assert!(!ctr.add_be(0x0102));
+    /// assert_eq!(ctr.expose_secret(), &[0x00, 0x01, 0x01, 0x01]);
+    ///
+    /// let mut near_max = Fixed::new([0xFF, 0xF0]);
+    /// assert!(near_max.add_be(0x10)); // overflow
+    /// assert_eq!(near_max.expose_secret(), &[0xFF, 0xF0]); // unchanged
+    /// ```
+    #[inline]
+    #[must_use = "a `true` return means the addition overflowed and was not applied"]
+    pub fn add_be(&mut self, addend: u64) -> bool {
+        if add_u64_be(&mut self.0, addend) {
+            sub_u64_be(&mut self.0, addend); // undo the wrap
+            return true;
+        }
+        false
+    }
+
+    /// Subtract `subtrahend`, treating the bytes as a big-endian integer.
+    ///
+    /// Returns `true` on **underflow** (the result would be negative), leaving the value
+    /// unchanged — the counterpart of [`add_be`](Self::add_be).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let mut ctr = Fixed::new([0x01, 0x00, 0x00]);
+    /// assert!(!ctr.sub_be(1));
+    /// assert_eq!(ctr.expose_secret(), &[0x00, 0xFF, 0xFF]);
+    /// assert!(ctr.sub_be(0x01_0000)); // underflow
+    /// assert_eq!(ctr.expose_secret(), &[0x00, 0xFF, 0xFF]);
+    /// ```
+    #[inline]
+    #[must_use = "a `true` return means the subtraction underflowed and was not applied"]
+    pub fn sub_be(&mut self, subtrahend: u64) -> bool {
+        if sub_u64_be(&mut self.0, subtrahend) {
+            add_u64_be(&mut self.0, subtrahend); // undo the wrap
+            return true;
+        }
+        false
+    }
+}
+
+// Heap copies — only available with `alloc` feature
+#[cfg(feature = "alloc")]
+impl<const N: usize> Fixed<[u8; N]> {
+    /// Copy the bytes into a new heap secret, leaving `self` untouched.
+    ///
+    /// Unlike `expose_secret().to_vec()`, the copy is protected from the moment it
+    /// exists: redacted `Debug`, explicit exposure, and wipe-on-demand under `zeroize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::{Dynamic, Fixed};
+    /// let key = Fixed::new([7u8; 16]);
+    /// let copy: Dynamic<Vec<u8>> = key.to_dynamic_copy();
+    /// assert_eq!(copy.expose_secret().as_slice(), key.expose_secret());
+    /// ```
+    #[inline]
+    pub fn to_dynamic_copy(&self) -> crate::Dynamic<alloc::vec::Vec<u8>> {
+        crate::Dynamic::new(self.0.to_vec())
+    }
+
+    /// Wrap a batch of raw keys in one call, e.g. when loading a keyring.
+    ///
+    /// Each array is copied into its own `Fixed`; `arrays` itself stays the caller's to
+    /// wipe. To wrap without the intermediate slice, use
+    /// [`SecretWrapExt::wrap_fixed`] on an iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::Fixed;
+    /// let keyring = Fixed::from_arrays(&[[1u8; 16], [2u8; 16]]);
+    /// assert_eq!(keyring.len(), 2);
+    /// assert_eq!(keyring[1].expose_secret(), &[2u8; 16]);
+    /// ```
+    pub fn from_arrays(arrays: &[[u8; N]]) -> alloc::vec::Vec<Self> {
+        arrays.iter().copied().map(Self::new).collect()
+    }
+}
+
+// Ripple-carry +1 from the least significant byte; returns the final carry (overflow).
+#[inline]
+fn add_one<'a>(bytes: impl Iterator<Item = &'a mut u8>) -> bool {
+    let mut carry = 1u16;
+    for b in bytes {
+        let sum = u16::from(*b) + carry;
+        *b = sum as u8;
+        carry = sum >> 8;
+    }
+    carry != 0
+}
+
+// Wrapping big-endian `bytes += v`; returns `true` if anything carried out of the array.
+#[inline]
+fn add_u64_be(bytes: &mut [u8], v: u64) -> bool {
+    let mut carry = v;
+    for b in bytes.iter_mut().rev() {
+        let sum = u16::from(*b) + (carry & 0xFF) as u16;
+        *b = sum as u8;
+        carry = (carry >> 8) + u64::from(sum >> 8);
+    }
+    carry != 0
+}
+
+// Wrapping big-endian `bytes -= v`; returns `true` if anything borrowed past the array.
+#[inline]
+fn sub_u64_be(bytes: &mut [u8], v: u64) -> bool {
+    let mut borrow = v;
+    for b in bytes.iter_mut().rev() {
+        let diff = u16::from(*b).wrapping_sub((borrow & 0xFF) as u16);
+        *b = diff as u8;
+        borrow = (borrow >> 8) + u64::from(diff >> 15);
+    }
+    borrow != 0
 }
 
 impl<const N: usize> From<[u8; N]> for Fixed<[u8; N]> {
@@ -194,6 +643,51 @@ impl<const N: usize> From<[u8; N]> for Fixed<[u8; N]> {
     }
 }
 
+/// **All zeros — a placeholder, NOT a key.**
+///
+/// Exists so structs with embedded `Fixed<[u8; N]>` fields can `#[derive(Default)]`.
+/// A zero key is public knowledge: overwrite it with real key material (or
+/// `Fixed::generate_random()`) before any cryptographic use.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::Fixed;
+/// let placeholder = Fixed::<[u8; 16]>::default();
+/// assert_eq!(placeholder.expose_secret(), &[0u8; 16]);
+/// ```
+impl<const N: usize> Default for Fixed<[u8; N]> {
+    #[inline]
+    fn default() -> Self {
+        Self::new([0u8; N])
+    }
+}
+
+/// Iterator adapter that wraps every item in a [`Fixed`] as it is produced.
+///
+/// Implemented for every iterator. Each raw value is moved straight into its wrapper,
+/// so bulk-loaded keys are protected as soon as they leave the source.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::{Fixed, SecretWrapExt};
+/// let raw = [[0xAAu8; 32], [0xBBu8; 32]];
+/// let keys: Vec<Fixed<[u8; 32]>> = raw.into_iter().wrap_fixed().collect();
+/// assert_eq!(keys[0].expose_secret(), &[0xAA; 32]);
+/// ```
+pub trait SecretWrapExt: Iterator + Sized {
+    /// Map each item `x` to `Fixed::new(x)`.
+    #[inline]
+    fn wrap_fixed(self) -> WrapFixed<Self> {
+        self.map(Fixed::new as fn(Self::Item) -> Fixed<Self::Item>)
+    }
+}
+
+type WrapFixed<I> = core::iter::Map<I, fn(<I as Iterator>::Item) -> Fixed<<I as Iterator>::Item>>;
+
+impl<I: Iterator> SecretWrapExt for I {}
+
 // Debug is always redacted
 impl<T> fmt::Debug for Fixed<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -202,6 +696,9 @@ impl<T> fmt::Debug for Fixed<T> {
 }
 
 // Explicit Clone only — no implicit Copy
+//
+// A clone is a fully independent copy: wiping (or dropping) it never touches the
+// original, and each copy is wiped on drop exactly when `T` wipes itself on drop.
 impl<T: Clone> Clone for Fixed<T> {
     #[inline(always)]
     fn clone(&self) -> Self {
@@ -237,6 +734,131 @@ impl<const N: usize> Fixed<[u8; N]> {
         self.expose_secret().ct_eq(other.expose_secret())
     }
 
+    /// Constant-time comparison against a runtime-length slice (e.g. parsed input).
+    ///
+    /// Returns `false` if `other.len() != N`. The length check depends only on the
+    /// public length `N`; when lengths match, contents are compared in constant time,
+    /// so timing never reveals where the bytes differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([7u8; 4]);
+    /// assert!(key.ct_eq_slice(&[7, 7, 7, 7]));
+    /// assert!(!key.ct_eq_slice(&[7, 7, 7]));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn ct_eq_slice(&self, other: &[u8]) -> bool {
+        use crate::conversions::SecureConversionsExt;
+        self.expose_secret().as_slice().ct_eq(other)
+    }
+
+    /// Constant-time membership test: `true` if any candidate equals `self`.
+    ///
+    /// Every candidate is compared in full and the results are OR-accumulated — an early
+    /// match does not stop the scan — so timing depends only on `candidates.len()`, never
+    /// on whether or which candidate matched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let valid = [Fixed::new([1u8; 8]), Fixed::new([2u8; 8]), Fixed::new([3u8; 8])];
+    /// assert!(Fixed::new([2u8; 8]).ct_eq_any(&valid));
+    /// assert!(!Fixed::new([4u8; 8]).ct_eq_any(&valid));
+    /// # }
+    /// ```
+    pub fn ct_eq_any(&self, candidates: &[Self]) -> bool {
+        use subtle::ConstantTimeEq;
+        candidates
+            .iter()
+            .fold(subtle::Choice::from(0), |found, candidate| {
+                found | self.0.ct_eq(&candidate.0)
+            })
+            .into()
+    }
+
+    /// Constant-time equality over only the bytes selected by `mask`.
+    ///
+    /// Position `i` is compared when `mask[i] != 0` and ignored otherwise — e.g. to skip
+    /// a version or flags byte in a structured secret. Every position is visited with the
+    /// same arithmetic, so timing depends on neither where the values differ nor which
+    /// bytes are masked. An all-zero mask compares equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let a = Fixed::new([1u8, 0xAA, 0xBB, 0xCC]); // byte 0 is a version field
+    /// let b = Fixed::new([2u8, 0xAA, 0xBB, 0xCC]);
+    /// assert!(a.ct_eq_masked(&b, &[0x00, 0xFF, 0xFF, 0xFF]));
+    /// assert!(!a.ct_eq_masked(&b, &[0xFF; 4]));
+    /// # }
+    /// ```
+    pub fn ct_eq_masked(&self, other: &Self, mask: &[u8; N]) -> bool {
+        use subtle::{ConditionallySelectable, ConstantTimeEq};
+        let mut diff = 0u8;
+        for ((a, b), m) in self.0.iter().zip(&other.0).zip(mask) {
+            let select = u8::conditional_select(&0xFF, &0x00, m.ct_eq(&0));
+            diff |= (a ^ b) & select;
+        }
+        diff.ct_eq(&0).into()
+    }
+
+    /// Constant-time ordering, treating both arrays as big-endian unsigned integers.
+    ///
+    /// Every byte pair is examined with no early exit, so timing does not depend on
+    /// where (or whether) the values differ. Intended for nonce/counter checks such as
+    /// reuse detection and interval tests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "conversions")]
+    /// # {
+    /// use core::cmp::Ordering;
+    /// use secure_gate::Fixed;
+    /// let a = Fixed::new([0x00, 0xFF]);
+    /// let b = Fixed::new([0x01, 0x00]);
+    /// assert_eq!(a.ct_cmp(&b), Ordering::Less);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn ct_cmp(&self, other: &Self) -> core::cmp::Ordering {
+        use subtle::{Choice, ConditionallySelectable, ConstantTimeGreater};
+
+        let mut gt = Choice::from(0);
+        let mut lt = Choice::from(0);
+        // Least significant byte first, so the most significant difference wins
+        for (a, b) in self.0.iter().zip(other.0.iter()).rev() {
+            let a_gt = a.ct_gt(b);
+            let b_gt = b.ct_gt(a);
+            let differs = a_gt | b_gt;
+            gt = Choice::conditional_select(&gt, &a_gt, differs);
+            lt = Choice::conditional_select(&lt, &b_gt, differs);
+        }
+
+        if bool::from(gt) {
+            core::cmp::Ordering::Greater
+        } else if bool::from(lt) {
+            core::cmp::Ordering::Less
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+}
+
+// Encoded-string constructors — decode through a temporary heap buffer
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl<const N: usize> Fixed<[u8; N]> {
     /// Create a `Fixed` secret from a hex string.
     ///
     /// Returns `Err` if the hex string is invalid or doesn't match the expected length.
@@ -254,6 +876,7 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// # Ok::<(), &'static str>(())
     /// ```
     pub fn from_hex(hex: &str) -> Result<Self, &'static str> {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
         let mut bytes = hex::decode(hex)
             .map_err(|_| "invalid hex string")?;
         
@@ -293,6 +916,7 @@ impl<const N: usize> Fixed<[u8; N]> {
         use base64::engine::general_purpose::URL_SAFE_NO_PAD;
         use base64::Engine;
         
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
         let mut bytes = URL_SAFE_NO_PAD.decode(b64)
             .map_err(|_| "invalid base64url string")?;
         
@@ -310,8 +934,8 @@ impl<const N: usize> Fixed<[u8; N]> {
     }
 }
 
-// Random generation — only available with `rand` feature
-#[cfg(feature = "rand")]
+// Random generation — only available with `rand-core` feature
+#[cfg(feature = "rand-core")]
 impl<const N: usize> Fixed<[u8; N]> {
     /// Generate fresh random bytes using the OS RNG.
     ///
@@ -328,13 +952,87 @@ impl<const N: usize> Fixed<[u8; N]> {
     /// let key: Fixed<[u8; 32]> = Fixed::generate_random();
     /// # }
     /// ```
+    #[cfg(feature = "rand")]
     #[inline]
     pub fn generate_random() -> Self {
         crate::rng::FixedRng::<N>::generate().into_inner()
     }
+
+    /// Generate random bytes from a caller-provided RNG.
+    ///
+    /// Intended for tests: a seeded RNG gives reproducible "random" keys. Production code
+    /// should use [`generate_random`](Self::generate_random), which always uses `OsRng`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand-core")]
+    /// # {
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use secure_gate::Fixed;
+    /// let a = Fixed::<[u8; 16]>::generate_random_with(&mut StdRng::seed_from_u64(7));
+    /// let b = Fixed::<[u8; 16]>::generate_random_with(&mut StdRng::seed_from_u64(7));
+    /// assert_eq!(a.expose_secret(), b.expose_secret());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn generate_random_with<R: rand::RngCore + rand::CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; N];
+        rng.fill_bytes(&mut bytes);
+        Self::new(bytes)
+    }
+
+    /// Generate a uniformly random big-endian integer strictly less than `bound`.
+    ///
+    /// Uses rejection sampling from the OS RNG: leading bytes above the bound's top
+    /// non-zero byte are fixed to zero and the top byte is masked to the bound's bit
+    /// length, so each attempt succeeds with probability > 1/2.
+    ///
+    /// The accept/reject comparison is not constant-time; it leaks only how many
+    /// candidates were rejected, never the returned value.
+    ///
+    /// Panics if `bound` is zero, or if the OS RNG is unavailable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let bound = [0x00, 0x00, 0x01, 0x00]; // 256
+    /// let n = Fixed::<[u8; 4]>::generate_random_below(&bound);
+    /// assert!(n.expose_secret() < &bound);
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_random_below(bound: &[u8; N]) -> Self {
+        let top = bound
+            .iter()
+            .position(|&b| b != 0)
+            .expect("generate_random_below: bound must be non-zero");
+        let mask = u8::MAX >> bound[top].leading_zeros();
+
+        let mut bytes = [0u8; N];
+        loop {
+            crate::rng::fill_fresh(&mut bytes[top..]).unwrap_or_else(|e| panic!("{e}"));
+            bytes[top] &= mask;
+            // Arrays compare lexicographically, i.e. as big-endian integers
+            if bytes < *bound {
+                return Self::new(bytes);
+            }
+        }
+    }
 }
 
 // Zeroize integration
+//
+// The only bound is `T: Zeroize`, so any inner type works — arrays, integers, or a
+// `#[derive(Zeroize)]` key schedule. `Fixed` itself has no `Drop` (a `Drop` impl cannot
+// carry the `T: Zeroize` bound), so for a custom `T` to be wiped when dropped, derive
+// `ZeroizeOnDrop` on `T` as well.
+//
+// For the same reason `ZeroizeOnDrop` is only claimed when `T` wipes itself: a plain
+// `Fixed<[u8; N]>` is *not* wiped on drop and does not carry the marker.
 #[cfg(feature = "zeroize")]
 impl<T: zeroize::Zeroize> zeroize::Zeroize for Fixed<T> {
     fn zeroize(&mut self) {
@@ -343,4 +1041,4 @@ impl<T: zeroize::Zeroize> zeroize::Zeroize for Fixed<T> {
 }
 
 #[cfg(feature = "zeroize")]
-impl<T: zeroize::Zeroize> zeroize::ZeroizeOnDrop for Fixed<T> {}
+impl<T: zeroize::ZeroizeOnDrop> zeroize::ZeroizeOnDrop for Fixed<T> {}
@@ -0,0 +1,172 @@
+// ==========================================================================
+// src/interop.rs
+// ==========================================================================
+
+// Bridges to other secret-handling crates. Each bridge is behind its own feature.
+
+#[cfg(any(feature = "secrecy", feature = "bytes"))]
+use crate::Dynamic;
+#[cfg(any(feature = "secrecy", feature = "generic-array"))]
+use crate::Fixed;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// secrecy
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "secrecy")]
+impl<T: ?Sized + zeroize::Zeroize> Dynamic<T> {
+    /// Move the secret into a `secrecy::SecretBox<T>`.
+    ///
+    /// The `Box<T>` is handed over as-is — no copy, no reallocation.
+    ///
+    /// There is no reverse conversion: `SecretBox` offers no way to move its box out,
+    /// and copying through `expose_secret()` would silently duplicate the secret.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "secrecy")]
+    /// # {
+    /// use secrecy::ExposeSecret;
+    /// use secure_gate::Dynamic;
+    /// let secret = Dynamic::<String>::new("hunter2".to_string());
+    /// let boxed: secrecy::SecretBox<String> = secret.into_secrecy();
+    /// assert_eq!(boxed.expose_secret(), "hunter2");
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn into_secrecy(self) -> secrecy::SecretBox<T> {
+        secrecy::SecretBox::new(self.into_box())
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl<T: ?Sized + zeroize::Zeroize> From<Dynamic<T>> for secrecy::SecretBox<T> {
+    /// Move a `Dynamic<T>` into a `secrecy::SecretBox<T>` without copying.
+    #[inline(always)]
+    fn from(secret: Dynamic<T>) -> Self {
+        secret.into_secrecy()
+    }
+}
+
+// `ExposeSecret` / `ExposeSecretMut` let `Fixed` and `Dynamic` flow into code written
+// against `secrecy`'s traits. They forward to the inherent methods of the same name.
+
+#[cfg(feature = "secrecy")]
+impl<T> secrecy::ExposeSecret<T> for Fixed<T> {
+    #[inline(always)]
+    fn expose_secret(&self) -> &T {
+        Fixed::expose_secret(self)
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl<T> secrecy::ExposeSecretMut<T> for Fixed<T> {
+    #[inline(always)]
+    fn expose_secret_mut(&mut self) -> &mut T {
+        Fixed::expose_secret_mut(self)
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl<T: ?Sized> secrecy::ExposeSecret<T> for Dynamic<T> {
+    #[inline(always)]
+    fn expose_secret(&self) -> &T {
+        Dynamic::expose_secret(self)
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl<T: ?Sized> secrecy::ExposeSecretMut<T> for Dynamic<T> {
+    #[inline(always)]
+    fn expose_secret_mut(&mut self) -> &mut T {
+        Dynamic::expose_secret_mut(self)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// generic-array (RustCrypto)
+// ─────────────────────────────────────────────────────────────────────────────
+
+// generic-array >= 0.14.8 marks its whole API deprecated in favour of 1.x, but the
+// RustCrypto 0.10-era traits still take the 0.14 types — so that is what we bridge to.
+#[cfg(feature = "generic-array")]
+#[allow(deprecated)]
+impl<const N: usize> Fixed<[u8; N]> {
+    /// Borrow the secret as a `&GenericArray<u8, U>` — no copy.
+    ///
+    /// `U` is usually inferred from the RustCrypto API being called. A length mismatch
+    /// (`U::USIZE != N`) is a compile-time error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "generic-array")]
+    /// # {
+    /// use generic_array::{typenum::U32, GenericArray};
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([7u8; 32]);
+    /// let ga: &GenericArray<u8, U32> = key.as_generic_array();
+    /// assert_eq!(ga.as_slice(), &[7u8; 32]);
+    /// # }
+    /// ```
+    #[inline(always)]
+    pub fn as_generic_array<U: generic_array::ArrayLength<u8>>(
+        &self,
+    ) -> &generic_array::GenericArray<u8, U> {
+        const { assert!(U::USIZE == N, "GenericArray length must equal N") };
+        generic_array::GenericArray::from_slice(self.expose_secret())
+    }
+
+    /// Copy a `GenericArray<u8, U>` into a new `Fixed<[u8; N]>`.
+    ///
+    /// With `zeroize`, the source array is wiped after the copy. A length mismatch
+    /// (`U::USIZE != N`) is a compile-time error.
+    #[inline]
+    pub fn from_generic_array<U: generic_array::ArrayLength<u8>>(
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        mut arr: generic_array::GenericArray<u8, U>,
+    ) -> Self {
+        const { assert!(U::USIZE == N, "GenericArray length must equal N") };
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&arr);
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(arr.as_mut_slice());
+        Self::new(bytes)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// bytes
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "bytes")]
+impl Dynamic<alloc::vec::Vec<u8>> {
+    /// Hand the secret buffer to a ref-counted `bytes::Bytes` — **opting out of wiping**.
+    ///
+    /// # Security
+    ///
+    /// Like `Box::leak`, this gives up secure-gate's guarantees: `Bytes` is shared,
+    /// cheaply cloned, and freed by whichever handle is dropped last, **without zeroizing**.
+    /// Every clone and slice is a plain, un-redacted view of the secret. Only use this
+    /// when the bytes are about to be written out anyway (e.g. a network frame).
+    ///
+    /// The `Vec` is moved into `Bytes` without copying, so no un-wiped duplicate is
+    /// left behind by the conversion itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "bytes")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let frame = Dynamic::<Vec<u8>>::new(b"ciphertext".to_vec());
+    /// let shared: bytes::Bytes = frame.into_bytes_shared(); // no longer wiped!
+    /// assert_eq!(&shared[..], b"ciphertext");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn into_bytes_shared(self) -> bytes::Bytes {
+        bytes::Bytes::from(*self.into_box())
+    }
+}
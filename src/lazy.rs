@@ -0,0 +1,99 @@
+// ==========================================================================
+// src/lazy.rs
+// ==========================================================================
+
+//! Lazily derived heap secrets.
+//!
+//! [`LazySecret`] holds an initializer and runs it at most once, on first access, storing
+//! the result as a [`Dynamic`]. Until then no secret exists in memory at all — useful
+//! for keys that are expensive to derive and often not needed.
+//!
+//! It is single-threaded (`!Sync`), like `core::cell::OnceCell`.
+
+use crate::Dynamic;
+use core::cell::{Cell, OnceCell};
+use core::fmt;
+
+/// A secret derived on first use, then held as a [`Dynamic<T>`].
+///
+/// `Debug` is always redacted. With `zeroize`, `T` must be `Zeroize` and the value is
+/// wiped when the `LazySecret` is dropped (if it was ever initialized).
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::LazySecret;
+/// let key = LazySecret::new(|| vec![0x42u8; 32]); // e.g. an expensive KDF
+/// assert!(!key.is_initialized());
+///
+/// assert_eq!(key.get_or_init().expose_secret()[0], 0x42);
+/// assert!(key.is_initialized());
+/// ```
+pub struct LazySecret<T, F = fn() -> T> {
+    cell: OnceCell<Dynamic<T>>,
+    init: Cell<Option<F>>,
+    #[cfg(feature = "zeroize")]
+    wipe: fn(&mut Dynamic<T>),
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize, F: FnOnce() -> T> LazySecret<T, F> {
+    /// Store `init` without running it.
+    #[inline]
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: OnceCell::new(),
+            init: Cell::new(Some(init)),
+            wipe: zeroize::Zeroize::zeroize,
+        }
+    }
+}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T, F: FnOnce() -> T> LazySecret<T, F> {
+    /// Store `init` without running it.
+    #[inline]
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: OnceCell::new(),
+            init: Cell::new(Some(init)),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> LazySecret<T, F> {
+    /// Return the secret, running the initializer first if this is the first access.
+    ///
+    /// The initializer runs at most once. If it panics, the `LazySecret` is poisoned and
+    /// every later call panics too.
+    pub fn get_or_init(&self) -> &Dynamic<T> {
+        self.cell.get_or_init(|| {
+            let init = self
+                .init
+                .take()
+                .expect("LazySecret initializer panicked earlier");
+            Dynamic::new(init())
+        })
+    }
+
+    /// Returns `true` once the initializer has run — safe public metadata.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T, F> Drop for LazySecret<T, F> {
+    fn drop(&mut self) {
+        if let Some(secret) = self.cell.get_mut() {
+            (self.wipe)(secret);
+        }
+    }
+}
+
+impl<T, F> fmt::Debug for LazySecret<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
@@ -2,35 +2,110 @@
 // src/lib.rs
 // ==========================================================================
 
+#![no_std]
 #![cfg_attr(not(feature = "zeroize"), forbid(unsafe_code))]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-// ── Core secret types (always available) ─────────────────────────────
+// ── Core secret types ────────────────────────────────────────────────
+#[cfg(feature = "alloc")]
 mod dynamic;
 mod fixed;
 
-pub use dynamic::Dynamic;
-pub use fixed::Fixed;
+#[cfg(feature = "alloc")]
+pub use dynamic::{Dynamic, DynamicIntoIter, SecretGuard};
+#[cfg(feature = "audit")]
+pub use dynamic::{AuditedDynamicVec, ExposureGuard};
+pub use fixed::{Fixed, SecretWrapExt};
 
-// ── Non-cloneable wrappers (always available, zero-cost, pure) ───────
+// ── Traits shared across wrapper families ────────────────────────────
+pub mod traits;
+pub use traits::CloneSecret;
+
+// ── Non-cloneable wrappers (zero-cost, pure) ─────────────────────────
 mod no_clone;
-pub use no_clone::{DynamicNoClone, FixedNoClone};
+#[cfg(feature = "alloc")]
+pub use no_clone::DynamicNoClone;
+pub use no_clone::FixedNoClone;
+
+// ── Build-then-freeze assembly ───────────────────────────────────────
+#[cfg(feature = "alloc")]
+pub mod builder;
+#[cfg(feature = "alloc")]
+pub use builder::SecretBuilder;
+
+// ── Reference-counted read-only secrets ─────────────────────────────
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub mod shared;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use shared::SharedSecret;
+
+// ── Lazily derived secrets ───────────────────────────────────────────
+#[cfg(feature = "alloc")]
+pub mod lazy;
+#[cfg(feature = "alloc")]
+pub use lazy::LazySecret;
+
+// ── Alignment-guaranteed fixed secrets ───────────────────────────────
+pub mod aligned;
+pub use aligned::AlignedFixed;
+
+// ── Fixed-capacity, variable-length stack secrets ────────────────────
+pub mod stack_dynamic;
+pub use stack_dynamic::StackDynamic;
 
 // ── Macros (always available) ────────────────────────────────────────
 mod macros;
 
+// Paths used by macro expansions — not public API
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::decode_hex_literal;
+    #[cfg(feature = "alloc")]
+    pub use alloc::string::String;
+    #[cfg(feature = "zeroize")]
+    pub use zeroize;
+}
+
 // ── Feature-gated modules (zero compile-time cost when disabled) ─────
-#[cfg(feature = "rand")]
+#[cfg(feature = "rand-core")]
 pub mod rng;
 
+#[cfg(all(feature = "rand", feature = "alloc"))]
+mod sharing;
+
 #[cfg(feature = "conversions")]
 pub mod conversions;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "audit")]
+pub mod audit;
+
+#[cfg(feature = "prompt")]
+pub mod prompt;
+
+#[cfg(any(feature = "secrecy", feature = "generic-array", feature = "bytes"))]
+mod interop;
+
 // ── Feature-gated re-exports ─────────────────────────────────────────
-#[cfg(feature = "rand")]
-pub use rng::{DynamicRng, FixedRng};
+#[cfg(all(feature = "rand-core", feature = "alloc"))]
+pub use rng::DynamicRng;
+#[cfg(all(feature = "rand", feature = "alloc"))]
+pub use rng::EntropyPool;
+#[cfg(feature = "rand-core")]
+pub use rng::FixedRng;
+#[cfg(feature = "shared-rng")]
+pub use rng::SharedRng;
 
 #[cfg(feature = "conversions")]
-pub use conversions::{HexString, RandomHex, SecureConversionsExt};
+pub use conversions::SecureConversionsExt;
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+pub use conversions::HexString;
+#[cfg(all(feature = "conversions", feature = "alloc", feature = "rand"))]
+pub use conversions::RandomHex;
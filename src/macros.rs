@@ -98,15 +98,92 @@ macro_rules! fixed_alias_rng {
     };
 }
 
+/// Creates a domain key **newtype** over `Fixed<[u8; N]>` with its helpers built in.
+///
+/// Unlike [`fixed_alias!`](crate::fixed_alias), which only names `Fixed<[u8; N]>`, this
+/// defines a distinct struct — an `Aes256Key` cannot be passed where an `HmacKey` is
+/// expected — with these inherent methods:
+///
+/// - `LEN`, `from_bytes([u8; N])`, `expose_secret()`, `into_inner() -> Fixed<[u8; N]>`,
+///   and a redacted `Debug` — always;
+/// - `generate()` — with the `rand` feature;
+/// - `ct_eq(&other)` — with `conversions`;
+/// - `to_hex() -> Dynamic<String>` — with `conversions` + `alloc`. The encoding is
+///   returned already wrapped, since the newtype has no explicit exposure step before it;
+/// - `Zeroize`, `ZeroizeOnDrop`, and a `Drop` that wipes the key — with `zeroize`.
+///
+/// The features are those of secure-gate, not of the calling crate.
+///
+/// # Examples
+///
+/// ```
+/// use secure_gate::keyed_alias;
+/// keyed_alias!(pub Aes256Key, 32);
+///
+/// let key = Aes256Key::from_bytes([0x42; 32]);
+/// assert_eq!(Aes256Key::LEN, 32);
+/// assert_eq!(key.expose_secret()[0], 0x42);
+/// assert_eq!(format!("{key:?}"), "[REDACTED]");
+/// # #[cfg(all(feature = "rand", feature = "conversions"))]
+/// # {
+/// let fresh = Aes256Key::generate();
+/// assert!(!fresh.ct_eq(&key));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! keyed_alias {
+    ($vis:vis $name:ident, $size:literal) => {
+        #[doc = concat!("Domain key type: a ", $size, "-byte `Fixed` secret.")]
+        $vis struct $name($crate::Fixed<[u8; $size]>);
+
+        impl $name {
+            /// Key length in bytes.
+            $vis const LEN: usize = $size;
+
+            /// Wrap raw key bytes.
+            #[inline(always)]
+            $vis const fn from_bytes(bytes: [u8; $size]) -> Self {
+                Self($crate::Fixed::new(bytes))
+            }
+
+            /// Expose the key bytes for read-only access.
+            #[inline(always)]
+            $vis const fn expose_secret(&self) -> &[u8; $size] {
+                self.0.expose_secret()
+            }
+
+            /// Unwrap into the underlying `Fixed` secret.
+            #[inline(always)]
+            $vis fn into_inner(mut self) -> $crate::Fixed<[u8; $size]> {
+                ::core::mem::replace(&mut self.0, $crate::Fixed::new([0u8; $size]))
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str("[REDACTED]")
+            }
+        }
+
+        $crate::__keyed_alias_rand!($vis $name, $size);
+        $crate::__keyed_alias_ct_eq!($vis $name);
+        $crate::__keyed_alias_hex!($vis $name);
+        $crate::__keyed_alias_zeroize!($name);
+    };
+}
+
 /// Creates a type alias for a heap-allocated secure secret.
 ///
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
 /// use secure_gate::dynamic_alias;
 /// dynamic_alias!(pub Password, String);
 /// let pw: Password = "hunter2".into();
 /// assert_eq!(pw.expose_secret(), "hunter2");
+/// # }
 /// ```
 #[macro_export]
 macro_rules! dynamic_alias {
@@ -121,10 +198,13 @@ macro_rules! dynamic_alias {
 /// # Examples
 ///
 /// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
 /// use secure_gate::dynamic_generic_alias;
 /// dynamic_generic_alias!(pub SecureVec, Vec<u8>, "Secure dynamic byte vector");
 /// let vec = SecureVec::new(vec![1, 2, 3]);
 /// assert_eq!(vec.len(), 3);
+/// # }
 /// ```
 #[macro_export]
 macro_rules! dynamic_generic_alias {
@@ -137,3 +217,317 @@ macro_rules! dynamic_generic_alias {
         $vis type $name = $crate::Dynamic<$inner>;
     };
 }
+
+/// Defines a struct whose every field is a wrapped secret.
+///
+/// Each field of type `T` becomes `Fixed<T>`, so every read goes through
+/// `.expose_secret()`. The generated struct gets:
+/// - a whole-struct `[REDACTED]` `Debug` impl,
+/// - a `new(..)` constructor taking the raw field values in declaration order,
+/// - with the `zeroize` feature: `Zeroize` (wipes each field), `ZeroizeOnDrop`, and a
+///   `Drop` impl that wipes every field. All field types must then implement `Zeroize`.
+///
+/// # Examples
+///
+/// ```
+/// use secure_gate::secret_struct;
+/// secret_struct! {
+///     pub struct Creds {
+///         pub key: [u8; 32],
+///         pub password: String,
+///     }
+/// }
+///
+/// let creds = Creds::new([0x42; 32], "hunter2".into());
+/// assert_eq!(creds.password.expose_secret(), "hunter2");
+/// assert_eq!(format!("{creds:?}"), "[REDACTED]");
+/// ```
+#[macro_export]
+macro_rules! secret_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$fmeta:meta])* $fvis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $( $(#[$fmeta])* $fvis $field: $crate::Fixed<$ty>, )*
+        }
+
+        impl $name {
+            #[doc = concat!("Create a new `", stringify!($name), "`, wrapping each field.")]
+            #[allow(clippy::too_many_arguments)]
+            $vis fn new($( $field: $ty ),*) -> Self {
+                Self { $( $field: $crate::Fixed::new($field), )* }
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str("[REDACTED]")
+            }
+        }
+
+        $crate::__secret_struct_zeroize!($name { $( $field ),* });
+    };
+}
+
+/// Asserts that two secrets are equal, comparing in constant time.
+///
+/// Works with anything that has a `ct_eq` method — `Fixed`, `Dynamic`, or raw byte
+/// arrays/slices via [`SecureConversionsExt`](crate::SecureConversionsExt). Unlike
+/// `assert_eq!`, a failure never prints the contents, only the expressions:
+///
+/// ```text
+/// assert_secret_eq!(a, b) failed: [REDACTED] != [REDACTED]
+/// ```
+///
+/// An optional trailing format string and arguments are appended to the message.
+///
+/// Requires the `conversions` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "conversions")]
+/// # {
+/// use secure_gate::{assert_secret_eq, Fixed};
+/// let a = Fixed::new([7u8; 32]);
+/// let b = Fixed::new([7u8; 32]);
+/// assert_secret_eq!(a, b);
+/// assert_secret_eq!(a, b, "key mismatch for slot {}", 3);
+/// # }
+/// ```
+///
+/// ```should_panic
+/// # #[cfg(feature = "conversions")]
+/// # {
+/// use secure_gate::{assert_secret_eq, Fixed};
+/// assert_secret_eq!(Fixed::new([1u8; 4]), Fixed::new([2u8; 4])); // panics, redacted
+/// # }
+/// # #[cfg(not(feature = "conversions"))]
+/// # panic!();
+/// ```
+#[cfg(feature = "conversions")]
+#[macro_export]
+macro_rules! assert_secret_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                #[allow(unused_imports)]
+                use $crate::SecureConversionsExt as _;
+                if !left.ct_eq(right) {
+                    ::core::panic!(
+                        "assert_secret_eq!({}, {}) failed: [REDACTED] != [REDACTED]",
+                        ::core::stringify!($left),
+                        ::core::stringify!($right),
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                #[allow(unused_imports)]
+                use $crate::SecureConversionsExt as _;
+                if !left.ct_eq(right) {
+                    ::core::panic!(
+                        "assert_secret_eq!({}, {}) failed: [REDACTED] != [REDACTED]: {}",
+                        ::core::stringify!($left),
+                        ::core::stringify!($right),
+                        ::core::format_args!($($arg)+),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Builds a `Fixed<[u8; N]>` from a hex string literal, decoded at compile time.
+///
+/// `N` is inferred from the literal (two digits per byte). Upper- and lowercase digits
+/// are accepted. An odd number of digits or a non-hex character is a compile error, not
+/// a runtime panic. Works in `no_std` and needs no features.
+///
+/// The bytes are a constant in the compiled binary — use this for test vectors and
+/// public constants, never for real keys.
+///
+/// # Examples
+///
+/// ```
+/// use secure_gate::{fixed_hex, Fixed};
+/// let key: Fixed<[u8; 4]> = fixed_hex!("DEADbeef");
+/// assert_eq!(key.expose_secret(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+/// ```
+///
+/// ```compile_fail
+/// let odd = secure_gate::fixed_hex!("abc"); // error: odd number of hex digits
+/// ```
+///
+/// ```compile_fail
+/// let bad = secure_gate::fixed_hex!("zz"); // error: invalid hex digit
+/// ```
+#[macro_export]
+macro_rules! fixed_hex {
+    ($hex:literal) => {{
+        const BYTES: [u8; $hex.len() / 2] = $crate::__private::decode_hex_literal($hex);
+        $crate::Fixed::new(BYTES)
+    }};
+}
+
+// Const-evaluated decoder behind `fixed_hex!`; panics surface as compile errors.
+#[doc(hidden)]
+pub const fn decode_hex_literal<const N: usize>(hex: &str) -> [u8; N] {
+    let digits = hex.as_bytes();
+    assert!(
+        digits.len().is_multiple_of(2),
+        "fixed_hex!: odd number of hex digits"
+    );
+    assert!(
+        digits.len() == 2 * N,
+        "fixed_hex!: length does not match the array size"
+    );
+    let mut out = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = (hex_nibble(digits[2 * i]) << 4) | hex_nibble(digits[2 * i + 1]);
+        i += 1;
+    }
+    out
+}
+
+const fn hex_nibble(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("fixed_hex!: invalid hex digit"),
+    }
+}
+
+// The `zeroize` feature must be checked here, inside secure-gate, rather than in the
+// expansion of `secret_struct!` — a `cfg` there would test the *caller's* features.
+#[doc(hidden)]
+#[cfg(feature = "zeroize")]
+#[macro_export]
+macro_rules! __secret_struct_zeroize {
+    ($name:ident { $( $field:ident ),* }) => {
+        impl $crate::__private::zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                $( $crate::__private::zeroize::Zeroize::zeroize(&mut self.$field); )*
+            }
+        }
+
+        impl $crate::__private::zeroize::ZeroizeOnDrop for $name {}
+
+        impl ::core::ops::Drop for $name {
+            fn drop(&mut self) {
+                $crate::__private::zeroize::Zeroize::zeroize(self);
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "zeroize"))]
+#[macro_export]
+macro_rules! __secret_struct_zeroize {
+    ($name:ident { $( $field:ident ),* }) => {};
+}
+
+// `keyed_alias!` helpers — feature checks resolved inside secure-gate, as above.
+#[doc(hidden)]
+#[cfg(feature = "rand")]
+#[macro_export]
+macro_rules! __keyed_alias_rand {
+    ($vis:vis $name:ident, $size:literal) => {
+        impl $name {
+            /// Generate a fresh random key from the OS RNG. Panics if the RNG fails.
+            #[inline]
+            $vis fn generate() -> Self {
+                Self($crate::Fixed::<[u8; $size]>::generate_random())
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "rand"))]
+#[macro_export]
+macro_rules! __keyed_alias_rand {
+    ($vis:vis $name:ident, $size:literal) => {};
+}
+
+#[doc(hidden)]
+#[cfg(feature = "conversions")]
+#[macro_export]
+macro_rules! __keyed_alias_ct_eq {
+    ($vis:vis $name:ident) => {
+        impl $name {
+            /// Constant-time equality.
+            #[inline]
+            $vis fn ct_eq(&self, other: &Self) -> bool {
+                self.0.ct_eq(&other.0)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "conversions"))]
+#[macro_export]
+macro_rules! __keyed_alias_ct_eq {
+    ($vis:vis $name:ident) => {};
+}
+
+#[doc(hidden)]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+#[macro_export]
+macro_rules! __keyed_alias_hex {
+    ($vis:vis $name:ident) => {
+        impl $name {
+            /// Lowercase hex encoding, wrapped as a secret.
+            #[inline]
+            $vis fn to_hex(&self) -> $crate::Dynamic<$crate::__private::String> {
+                $crate::SecureConversionsExt::to_hex_secret(self.0.expose_secret())
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(all(feature = "conversions", feature = "alloc")))]
+#[macro_export]
+macro_rules! __keyed_alias_hex {
+    ($vis:vis $name:ident) => {};
+}
+
+#[doc(hidden)]
+#[cfg(feature = "zeroize")]
+#[macro_export]
+macro_rules! __keyed_alias_zeroize {
+    ($name:ident) => {
+        impl $crate::__private::zeroize::Zeroize for $name {
+            fn zeroize(&mut self) {
+                $crate::__private::zeroize::Zeroize::zeroize(&mut self.0);
+            }
+        }
+
+        impl $crate::__private::zeroize::ZeroizeOnDrop for $name {}
+
+        impl ::core::ops::Drop for $name {
+            fn drop(&mut self) {
+                $crate::__private::zeroize::Zeroize::zeroize(self);
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(not(feature = "zeroize"))]
+#[macro_export]
+macro_rules! __keyed_alias_zeroize {
+    ($name:ident) => {};
+}
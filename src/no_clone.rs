@@ -2,9 +2,8 @@
 // src/no_clone.rs
 // ==========================================================================
 
-extern crate alloc;
-
-use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::fmt;
 
 /// Non-cloneable stack-allocated secret wrapper.
@@ -24,13 +23,15 @@ use core::fmt;
 /// assert_eq!(no_clone.expose_secret()[0], 1);
 /// ```
 ///
-/// With `zeroize`:
+/// With `zeroize` (like [`Fixed`](crate::Fixed), there is no `Drop` — wipe explicitly):
 /// ```
 /// # #[cfg(feature = "zeroize")]
 /// # {
 /// use secure_gate::FixedNoClone;
+/// use zeroize::Zeroize;
 /// let mut secret = FixedNoClone::new([1u8, 2, 3]);
-/// drop(secret); // wiped on drop
+/// secret.zeroize();
+/// assert_eq!(secret.expose_secret(), &[0, 0, 0]);
 /// # }
 /// ```
 pub struct FixedNoClone<T>(T);
@@ -51,6 +52,10 @@ pub struct FixedNoClone<T>(T);
 /// // no_clone cannot be cloned
 /// assert_eq!(no_clone.expose_secret(), "hunter2");
 /// ```
+///
+/// Like [`Dynamic`](crate::Dynamic), there is no `Drop` — with `zeroize`, wipe with
+/// `zeroize_now` before the value goes out of scope.
+#[cfg(feature = "alloc")]
 pub struct DynamicNoClone<T: ?Sized>(Box<T>);
 
 impl<T> FixedNoClone<T> {
@@ -126,6 +131,7 @@ impl<T: Zeroize> FixedNoClone<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T: ?Sized> DynamicNoClone<T> {
     /// Wrap a boxed value in a non-cloneable dynamic secret.
     ///
@@ -176,7 +182,7 @@ impl<T: ?Sized> DynamicNoClone<T> {
 }
 
 // Explicit zeroization — only available with `zeroize` feature
-#[cfg(feature = "zeroize")]
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
 impl<T: ?Sized + Zeroize> DynamicNoClone<T> {
     /// Explicitly zeroize the secret immediately.
     ///
@@ -206,14 +212,40 @@ impl<T> fmt::Debug for FixedNoClone<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T: ?Sized> fmt::Debug for DynamicNoClone<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("[REDACTED]")
     }
 }
 
+// === Byte-array helpers ===
+
+impl<const N: usize> FixedNoClone<[u8; N]> {
+    /// Expose the secret as a `&[u8]` slice — for APIs that take slices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::{Fixed, FixedNoClone};
+    /// let key: FixedNoClone<[u8; 4]> = Fixed::new([7u8; 4]).no_clone();
+    /// assert_eq!(key.expose_slice(), &[7, 7, 7, 7]);
+    /// ```
+    #[inline(always)]
+    pub const fn expose_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Expose the secret as a `&mut [u8]` slice.
+    #[inline(always)]
+    pub fn expose_slice_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
 // === Ergonomic helpers for common heap types ===
 
+#[cfg(feature = "alloc")]
 impl DynamicNoClone<String> {
     /// Returns the length of the secret string in bytes (UTF-8).
     #[inline(always)]
@@ -228,6 +260,7 @@ impl DynamicNoClone<String> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> DynamicNoClone<Vec<T>> {
     /// Returns the length of the secret vector in elements.
     #[inline(always)]
@@ -242,6 +275,44 @@ impl<T> DynamicNoClone<Vec<T>> {
     }
 }
 
+// Capacity reclamation — only available with `zeroize` feature
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl DynamicNoClone<Vec<u8>> {
+    /// Shrink the capacity to the length, wiping the old allocation first.
+    ///
+    /// Same guarantee and cost as `Dynamic::<Vec<u8>>::shrink_and_wipe`: unlike
+    /// `shrink_to_fit`, no byte of the previous allocation is freed un-wiped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "zeroize")]
+    /// # {
+    /// use secure_gate::DynamicNoClone;
+    /// let mut v = Vec::with_capacity(64);
+    /// v.extend_from_slice(b"key");
+    /// let mut secret = DynamicNoClone::new(Box::new(v));
+    /// secret.shrink_and_wipe();
+    /// assert_eq!(secret.expose_secret().capacity(), 3);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn shrink_and_wipe(&mut self) {
+        crate::dynamic::shrink_and_wipe_vec(&mut self.0);
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl DynamicNoClone<String> {
+    /// Shrink the capacity to the length, wiping the old allocation first.
+    ///
+    /// Same guarantee and cost as [`DynamicNoClone::<Vec<u8>>::shrink_and_wipe`].
+    #[inline]
+    pub fn shrink_and_wipe(&mut self) {
+        crate::dynamic::shrink_and_wipe_string(&mut self.0);
+    }
+}
+
 #[cfg(feature = "zeroize")]
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -252,15 +323,17 @@ impl<T: Zeroize> Zeroize for FixedNoClone<T> {
     }
 }
 
-#[cfg(feature = "zeroize")]
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
 impl<T: ?Sized + Zeroize> Zeroize for DynamicNoClone<T> {
     fn zeroize(&mut self) {
         self.0.zeroize();
     }
 }
 
+// Only when `T` wipes itself — `FixedNoClone` has no `Drop` (see `Fixed`)
 #[cfg(feature = "zeroize")]
-impl<T: Zeroize> ZeroizeOnDrop for FixedNoClone<T> {}
+impl<T: ZeroizeOnDrop> ZeroizeOnDrop for FixedNoClone<T> {}
 
-#[cfg(feature = "zeroize")]
-impl<T: ?Sized + Zeroize> ZeroizeOnDrop for DynamicNoClone<T> {}
+// Likewise for `DynamicNoClone` (see `Dynamic`)
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl<T: ?Sized + ZeroizeOnDrop> ZeroizeOnDrop for DynamicNoClone<T> {}
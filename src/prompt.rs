@@ -0,0 +1,174 @@
+// ==========================================================================
+// src/prompt.rs
+// ==========================================================================
+
+//! Interactive password prompts.
+//!
+//! [`Dynamic::<String>::read_password`](crate::Dynamic::read_password) prints a prompt,
+//! turns off terminal echo, reads one line from stdin and wraps it — the secret goes
+//! straight from the terminal into a [`Dynamic`] without the caller ever handling a
+//! bare `String`.
+//!
+//! Echo is controlled with the system `stty` utility, so this module needs no extra
+//! dependencies and no `unsafe`. It is therefore Unix-only for terminals: elsewhere
+//! reading from a terminal fails with [`io::ErrorKind::Unsupported`] rather than
+//! echoing the password. When stdin is not a terminal (piped input) there is nothing
+//! to hide and the line is read as-is on every platform.
+
+use crate::Dynamic;
+use alloc::string::String;
+use alloc::vec::Vec;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+impl Dynamic<String> {
+    /// Print `prompt` to stderr, read one line from stdin with echo disabled, and wrap it.
+    ///
+    /// The trailing `\n` / `\r\n` is stripped. Echo is restored before returning — also
+    /// on error or panic. The line is collected with the crate's secure growth, so with
+    /// `zeroize` no partial copy is left in freed memory, and the buffer is wiped if the
+    /// input is rejected (e.g. not UTF-8). Bytes that passed through stdin's own internal
+    /// buffer are outside this crate's reach.
+    ///
+    /// # Errors
+    ///
+    /// Any I/O error from the terminal or stdin; [`io::ErrorKind::UnexpectedEof`] if
+    /// stdin is closed before anything is read; [`io::ErrorKind::InvalidData`] for
+    /// non-UTF-8 input; [`io::ErrorKind::Unsupported`] for a terminal on a
+    /// non-Unix platform.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use secure_gate::Dynamic;
+    /// let password = Dynamic::<String>::read_password("Password: ")?;
+    /// assert!(!password.is_empty());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn read_password(prompt: &str) -> io::Result<Self> {
+        let mut stderr = io::stderr().lock();
+        stderr.write_all(prompt.as_bytes())?;
+        stderr.flush()?;
+
+        let stdin = io::stdin();
+        let echo = if stdin.is_terminal() {
+            Some(EchoGuard::disable()?)
+        } else {
+            None
+        };
+
+        let mut line = Vec::new();
+        let result = read_line_secure(&mut stdin.lock(), &mut line);
+        if let Some(echo) = echo {
+            drop(echo);
+            // The user's Enter was not echoed either — end the prompt line ourselves.
+            let _ = stderr.write_all(b"\n");
+        }
+
+        if let Err(e) = result {
+            wipe(&mut line);
+            return Err(e);
+        }
+        match String::from_utf8(line) {
+            Ok(line) => Ok(Self::new(line)),
+            Err(e) => {
+                wipe(&mut e.into_bytes());
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "password is not valid UTF-8",
+                ))
+            }
+        }
+    }
+}
+
+// Reads up to and excluding the line terminator, growing `line` only through
+// `secure_reserve_vec`.
+fn read_line_secure(reader: &mut impl BufRead, line: &mut Vec<u8>) -> io::Result<()> {
+    let mut saw_any = false;
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(buf) => buf,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if available.is_empty() {
+            break;
+        }
+        saw_any = true;
+        let (chunk, done) = match available.iter().position(|&b| b == b'\n') {
+            Some(i) => (&available[..i], Some(i + 1)),
+            None => (available, None),
+        };
+        crate::dynamic::secure_reserve_vec(line, chunk.len());
+        line.extend_from_slice(chunk);
+        let consumed = done.unwrap_or(chunk.len());
+        reader.consume(consumed);
+        if done.is_some() {
+            break;
+        }
+    }
+    if !saw_any {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "no password entered",
+        ));
+    }
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "zeroize"), allow(unused_variables, clippy::ptr_arg))]
+fn wipe(bytes: &mut Vec<u8>) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(bytes);
+}
+
+// Disables terminal echo and restores the saved settings on drop.
+#[cfg_attr(not(unix), allow(dead_code))]
+struct EchoGuard {
+    #[cfg(unix)]
+    saved: String,
+}
+
+impl EchoGuard {
+    #[cfg(unix)]
+    fn disable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["-echo"])?;
+        Ok(Self {
+            saved: String::from(saved.trim()),
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn disable() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "disabling terminal echo is only supported on Unix",
+        ))
+    }
+}
+
+#[cfg(unix)]
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        let _ = stty(&[self.saved.as_str()]);
+    }
+}
+
+// `stty` acts on its stdin, which it inherits from us — the terminal.
+#[cfg(unix)]
+fn stty(args: &[&str]) -> io::Result<String> {
+    let out = std::process::Command::new("stty")
+        .args(args)
+        .stdin(std::process::Stdio::inherit())
+        .stderr(std::process::Stdio::null())
+        .output()?;
+    if !out.status.success() {
+        return Err(io::Error::other("stty failed to change terminal settings"));
+    }
+    String::from_utf8(out.stdout)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "unexpected stty output"))
+}
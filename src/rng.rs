@@ -2,16 +2,123 @@
 // src/rng.rs
 // ==========================================================================
 
-use crate::{Dynamic, Fixed};
+//! Type-safe, fresh randomness backed by the OS entropy source.
+//!
+//! All generation goes through `rand::rngs::OsRng`, which is `getrandom` under the hood.
+//! Those paths (`generate`, `try_generate`, `EntropyPool`, `SharedRng`, ...) need the
+//! `rand` feature. `rand-core` alone builds without OS entropy — e.g. on embedded targets —
+//! and keeps the caller-supplied-generator paths such as `DynamicRng::from_rng`.
+//!
+//! # WASM (`wasm32-unknown-unknown`)
+//!
+//! There is no OS entropy source in the browser, so `getrandom` refuses to compile for this
+//! target until a backend is selected. Enable the `wasm-js` feature *and* set the backend cfg:
+//!
+//! ```toml
+//! # Cargo.toml
+//! secure-gate = { version = "0.6", features = ["rand", "wasm-js"] }
+//! ```
+//!
+//! ```toml
+//! # .cargo/config.toml
+//! [target.wasm32-unknown-unknown]
+//! rustflags = ['--cfg', 'getrandom_backend="wasm_js"']
+//! ```
+//!
+//! The feature alone is not enough — without the cfg the build still fails with
+//! `getrandom`'s own compile error. If entropy is still unavailable at runtime (e.g. no
+//! `crypto.getRandomValues` in the host), `try_generate` returns [`RNG_UNAVAILABLE`]
+//! and `generate` panics with the same message.
+//!
+//! # Threads
+//!
+//! [`FixedRng`] and [`DynamicRng`] can be called freely from any thread. With `std`,
+//! `generate` / `try_generate` (and everything built on them) draw from a per-thread
+//! [`EntropyPool`]: a 4 KiB buffer of `OsRng` output, refilled when depleted, that serves
+//! many small requests per syscall and wipes bytes as it issues them. Without `std` there
+//! is no thread-local storage, so every call makes one OS entropy request.
+//!
+//! The pool's unissued bytes are future output: anyone who can read this thread's memory
+//! can predict them until they are drawn, and after `fork` the child inherits the pool
+//! of the forking thread and would repeat the parent's next values. Call
+//! `discard_thread_pool` in the child (and in any thread whose buffered output may have
+//! been observed).
+//!
+//! For sharing one generator across threads, the `shared-rng` feature adds `SharedRng`:
+//! a single ChaCha20 generator, seeded from `OsRng`, behind a `Mutex`. It trades the
+//! syscall for lock contention and keeps generator state in process memory until the
+//! next `SharedRng::reseed`. Reseed after `fork`.
+
+use crate::Fixed;
+#[cfg(feature = "alloc")]
+use crate::Dynamic;
+#[cfg(all(feature = "rand", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "rand")]
 use rand::rngs::OsRng;
+#[cfg(feature = "rand")]
 use rand::TryRngCore;
 
+/// Error returned by `try_generate` when the OS entropy source cannot be read.
+#[cfg(feature = "rand")]
+pub const RNG_UNAVAILABLE: &str = "OS entropy source unavailable — on wasm32-unknown-unknown \
+enable the `wasm-js` feature and build with RUSTFLAGS='--cfg getrandom_backend=\"wasm_js\"'";
+
+#[cfg(feature = "rand")]
+#[inline]
+pub(crate) fn fill_os(bytes: &mut [u8]) -> Result<(), &'static str> {
+    OsRng.try_fill_bytes(bytes).map_err(|_| RNG_UNAVAILABLE)
+}
+
+#[cfg(all(feature = "rand", feature = "std"))]
+std::thread_local! {
+    static THREAD_POOL: core::cell::RefCell<EntropyPool> =
+        core::cell::RefCell::new(EntropyPool::new());
+}
+
+// Fresh entropy for the `generate` paths: this thread's pool under `std`, otherwise (or
+// while the thread-local is being torn down) one OS request. `bytes` is wiped on error.
+#[cfg(feature = "rand")]
+#[inline]
+pub(crate) fn fill_fresh(bytes: &mut [u8]) -> Result<(), &'static str> {
+    #[cfg(feature = "std")]
+    if let Ok(result) = THREAD_POOL.try_with(|pool| pool.borrow_mut().fill(bytes)) {
+        return result;
+    }
+    fill_os(bytes).inspect_err(|_| wipe_bytes(bytes))
+}
+
+/// Wipe the unissued bytes of this thread's entropy pool, so the next `generate` refills
+/// from the OS.
+///
+/// Call it in a child process right after `fork` — otherwise parent and child draw the
+/// same buffered values — or whenever this thread's memory may have been observed.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "rand", feature = "std"))]
+/// # {
+/// use secure_gate::rng::{discard_thread_pool, FixedRng};
+/// let before = FixedRng::<16>::generate();
+/// discard_thread_pool();
+/// let after = FixedRng::<16>::generate();
+/// assert_ne!(before.expose_secret(), after.expose_secret());
+/// # }
+/// ```
+#[cfg(all(feature = "rand", feature = "std"))]
+pub fn discard_thread_pool() {
+    let _ = THREAD_POOL.try_with(|pool| pool.borrow_mut().discard());
+}
+
 /// Fixed-length cryptographically secure random value.
 ///
 /// This is a newtype over `Fixed<[u8; N]>` that enforces construction only via secure RNG.
 /// Guarantees freshness — cannot be created from arbitrary bytes.
 ///
-/// Requires the "rand" feature.
+/// Requires the "rand-core" feature.
 ///
 /// # Examples
 ///
@@ -39,8 +146,10 @@ pub struct FixedRng<const N: usize>(Fixed<[u8; N]>);
 impl<const N: usize> FixedRng<N> {
     /// Generate fresh random bytes using the OS RNG.
     ///
-    /// Uses `rand::rngs::OsRng` directly for maximum throughput.
-    /// Panics if the RNG fails (rare, but correct for crypto code).
+    /// With `std` the bytes come from this thread's entropy pool (see the module docs);
+    /// otherwise from `rand::rngs::OsRng` directly.
+    /// Panics if the RNG fails (rare, but correct for crypto code) — use
+    /// [`try_generate`](Self::try_generate) to handle that case instead.
     ///
     /// # Example
     ///
@@ -52,12 +161,31 @@ impl<const N: usize> FixedRng<N> {
     /// assert!(!random.is_empty());
     /// # }
     /// ```
+    #[cfg(feature = "rand")]
     pub fn generate() -> Self {
+        Self::try_generate().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Generate fresh random bytes, returning an error if the OS RNG is unavailable.
+    ///
+    /// The error is [`RNG_UNAVAILABLE`], which explains how to configure entropy on WASM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::FixedRng;
+    /// let random = FixedRng::<16>::try_generate()?;
+    /// assert_eq!(random.len(), 16);
+    /// # }
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn try_generate() -> Result<Self, &'static str> {
         let mut bytes = [0u8; N];
-        OsRng
-            .try_fill_bytes(&mut bytes)
-            .expect("OsRng failed — this should never happen on supported platforms");
-        Self(Fixed::new(bytes))
+        fill_fresh(&mut bytes)?;
+        Ok(Self(Fixed::new(bytes)))
     }
 
     /// Expose the random bytes for read-only access.
@@ -139,12 +267,148 @@ impl<const N: usize> From<FixedRng<N>> for Fixed<[u8; N]> {
     }
 }
 
+// Domain separation: the seed is never used directly as a BLAKE3 key, so derivations cannot
+// collide with any other keyed-BLAKE3 use of the same seed.
+const DERIVE_CONTEXT: &str = "secure-gate Fixed::derive_from_seed v1";
+
+impl<const N: usize> Fixed<[u8; N]> {
+    /// Deterministically derive `N` bytes of key material from `seed` and a domain `label`.
+    ///
+    /// The same `(seed, label)` always yields the same bytes; different labels yield
+    /// independent-looking outputs. Intended for test harnesses and per-context key
+    /// derivation from one master seed.
+    ///
+    /// **This is derivation, not fresh entropy**: the output is exactly as secret as
+    /// `seed`. Use [`FixedRng`] when you need new randomness.
+    ///
+    /// Construction: a BLAKE3 keyed hash of `label` (under a sub-key derived from `seed`
+    /// with a fixed context string) seeds a ChaCha20 DRBG, which produces the output. The
+    /// intermediate key is zeroized under `zeroize`; the generator's internal state is
+    /// not, because `rand_chacha` exposes no way to wipe it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand-core")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let master = Fixed::new([42u8; 32]);
+    /// let enc = Fixed::<[u8; 32]>::derive_from_seed(&master, b"encryption");
+    /// let mac = Fixed::<[u8; 32]>::derive_from_seed(&master, b"authentication");
+    /// assert_ne!(enc.expose_secret(), mac.expose_secret());
+    /// assert_eq!(
+    ///     enc.expose_secret(),
+    ///     Fixed::<[u8; 32]>::derive_from_seed(&master, b"encryption").expose_secret()
+    /// );
+    /// # }
+    /// ```
+    pub fn derive_from_seed(seed: &Fixed<[u8; 32]>, label: &[u8]) -> Self {
+        use rand::{RngCore, SeedableRng};
+
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut sub_key = blake3::derive_key(DERIVE_CONTEXT, seed.expose_secret());
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut drbg_key = *blake3::keyed_hash(&sub_key, label).as_bytes();
+        let mut drbg = rand_chacha::ChaCha20Rng::from_seed(drbg_key);
+        #[cfg(feature = "zeroize")]
+        {
+            zeroize::Zeroize::zeroize(&mut sub_key);
+            zeroize::Zeroize::zeroize(&mut drbg_key);
+        }
+
+        let mut out = [0u8; N];
+        drbg.fill_bytes(&mut out);
+        Self::new(out)
+    }
+
+    /// Generate `COUNT` random keys that are guaranteed pairwise distinct.
+    ///
+    /// Any key that duplicates an earlier one is re-rolled — astronomically unlikely for
+    /// real key sizes, but it makes the guarantee unconditional (useful for test scaffolding
+    /// and key hierarchies). Requesting more keys than there are `N`-byte values
+    /// (`COUNT > 256^N`) is a compile-time error rather than an endless loop.
+    ///
+    /// The duplicate check is not constant-time; it reveals only whether a re-roll happened.
+    /// Panics if the OS RNG is unavailable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let [a, b, c] = Fixed::<[u8; 16]>::generate_distinct::<3>();
+    /// assert_ne!(a.expose_secret(), b.expose_secret());
+    /// assert_ne!(b.expose_secret(), c.expose_secret());
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use secure_gate::Fixed;
+    /// let _ = Fixed::<[u8; 1]>::generate_distinct::<257>(); // only 256 one-byte values
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn generate_distinct<const COUNT: usize>() -> [Self; COUNT] {
+        const {
+            assert!(
+                N >= (usize::BITS / 8) as usize || COUNT <= 1usize << (8 * N),
+                "generate_distinct: COUNT exceeds the number of distinct N-byte keys"
+            )
+        };
+        let mut keys: [Self; COUNT] = core::array::from_fn(|_| Self::generate_random());
+        for i in 1..COUNT {
+            while keys[..i].iter().any(|k| k.expose_secret() == keys[i].expose_secret()) {
+                keys[i] = Self::generate_random();
+            }
+        }
+        keys
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Fixed<[u8; 32]> {
+    /// Expand this key into `count` 64-byte ChaCha20 keystream blocks.
+    ///
+    /// Construction: the ChaCha20 block function (20 rounds, original 64-bit-counter
+    /// layout as in `rand_chacha::ChaCha20Rng`) keyed by `self`, with an all-zero nonce
+    /// and block counter `0, 1, …, count - 1`. Block `i` is the keystream for counter `i`,
+    /// so the output is deterministic for a given key and matches the zero-nonce ChaCha20
+    /// test vectors.
+    ///
+    /// There is no nonce: the same key always produces the same keystream. Use each key
+    /// for **one** keystream only — XORing two messages with the same keystream reveals
+    /// their XOR. As with [`derive_from_seed`](Self::derive_from_seed), the generator's
+    /// internal state is not wiped. Panics if `count * 64` overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand-core")]
+    /// # {
+    /// use secure_gate::Fixed;
+    /// let key = Fixed::new([7u8; 32]);
+    /// let stream = key.keystream_blocks(4);
+    /// assert_eq!(stream.len(), 4 * 64);
+    /// assert_eq!(stream.expose_secret(), key.keystream_blocks(4).expose_secret());
+    /// # }
+    /// ```
+    pub fn keystream_blocks(&self, count: usize) -> Dynamic<Vec<u8>> {
+        use rand::{RngCore, SeedableRng};
+
+        let len = count.checked_mul(64).expect("keystream length overflows usize");
+        let mut chacha = rand_chacha::ChaCha20Rng::from_seed(*self.expose_secret());
+        let mut out = vec![0u8; len];
+        chacha.fill_bytes(&mut out);
+        Dynamic::new(out)
+    }
+}
+
 /// Heap-allocated cryptographically secure random bytes.
 ///
 /// This is a newtype over `Dynamic<Vec<u8>>` for semantic clarity.
 /// Like `FixedRng`, guarantees freshness via RNG construction.
 ///
-/// Requires the "rand" feature.
+/// Requires the "rand-core" feature.
 ///
 /// # Examples
 ///
@@ -156,12 +420,15 @@ impl<const N: usize> From<FixedRng<N>> for Fixed<[u8; N]> {
 /// assert_eq!(random.len(), 64);
 /// # }
 /// ```
+#[cfg(feature = "alloc")]
 pub struct DynamicRng(Dynamic<Vec<u8>>);
 
+#[cfg(feature = "alloc")]
 impl DynamicRng {
     /// Generate fresh random bytes of the specified length.
     ///
-    /// Panics if the RNG fails.
+    /// Drawn from this thread's entropy pool under `std`, like [`FixedRng::generate`].
+    /// Panics if the RNG fails — use [`try_generate`](Self::try_generate) to handle that case.
     ///
     /// # Example
     ///
@@ -172,14 +439,155 @@ impl DynamicRng {
     /// let random = DynamicRng::generate(128);
     /// # }
     /// ```
+    #[cfg(feature = "rand")]
     pub fn generate(len: usize) -> Self {
+        Self::try_generate(len).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Generate fresh random bytes, returning an error if the OS RNG is unavailable.
+    ///
+    /// The error is [`RNG_UNAVAILABLE`], which explains how to configure entropy on WASM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let random = DynamicRng::try_generate(64)?;
+    /// assert_eq!(random.len(), 64);
+    /// # }
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn try_generate(len: usize) -> Result<Self, &'static str> {
+        let mut bytes = vec![0u8; len];
+        fill_fresh(&mut bytes)?;
+        Ok(Self(Dynamic::from(bytes)))
+    }
+
+    /// Generate `len` random bytes from a caller-supplied cryptographic RNG instead of `OsRng`.
+    ///
+    /// For targets without an OS entropy source — seed a CSPRNG from a hardware TRNG and
+    /// pass it here. The `CryptoRng` bound rejects non-cryptographic generators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "rand-core", feature = "alloc"))]
+    /// # {
+    /// use rand::SeedableRng;
+    /// use secure_gate::rng::DynamicRng;
+    /// # let trng_seed = [7u8; 32];
+    /// // any `RngCore + CryptoRng`, e.g. ChaCha20 seeded from a hardware TRNG
+    /// let mut rng = rand_chacha::ChaCha20Rng::from_seed(trng_seed);
+    /// let random = DynamicRng::from_rng(&mut rng, 32);
+    /// assert_eq!(random.len(), 32);
+    /// # }
+    /// ```
+    pub fn from_rng<R: rand::CryptoRng + ?Sized>(rng: &mut R, len: usize) -> Self {
         let mut bytes = vec![0u8; len];
-        OsRng
-            .try_fill_bytes(&mut bytes)
-            .expect("OsRng failed — this should never happen on supported platforms");
+        rng.fill_bytes(&mut bytes);
         Self(Dynamic::from(bytes))
     }
 
+    /// Generate a random RFC 4122 version 4 UUID as a protected string.
+    ///
+    /// Output is lowercase hyphenated form (`xxxxxxxx-xxxx-4xxx-Nxxx-xxxxxxxxxxxx`, with
+    /// `N` one of `8 9 a b`), built from 16 bytes of `OsRng` output with the version and
+    /// variant bits set. The raw bytes are zeroized after formatting under `zeroize`.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let id = DynamicRng::rng_uuid_v4();
+    /// assert_eq!(id.len(), 36);
+    /// assert_eq!(&id.expose_secret()[14..15], "4");
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn rng_uuid_v4() -> Dynamic<String> {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        let mut bytes = [0u8; 16];
+        fill_fresh(&mut bytes).unwrap_or_else(|e| panic!("{e}"));
+        bytes[6] = (bytes[6] & 0x0f) | 0x40; // version 4
+        bytes[8] = (bytes[8] & 0x3f) | 0x80; // RFC 4122 variant
+
+        let mut uuid = String::with_capacity(36);
+        for (i, b) in bytes.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                uuid.push('-');
+            }
+            uuid.push(HEX[(b >> 4) as usize] as char);
+            uuid.push(HEX[(b & 0x0f) as usize] as char);
+        }
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut bytes);
+        Dynamic::new(uuid)
+    }
+
+    /// Generate `byte_len` random bytes and return them as a protected lowercase hex string
+    /// (`2 * byte_len` characters).
+    ///
+    /// The dynamic-length counterpart to `FixedRng::random_hex`. The raw bytes are
+    /// zeroized after encoding under `zeroize`.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "rand", feature = "conversions"))]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let token = DynamicRng::generate_hex(16);
+    /// assert_eq!(token.len(), 32);
+    /// # }
+    /// ```
+    #[cfg(all(feature = "rand", feature = "conversions"))]
+    pub fn generate_hex(byte_len: usize) -> Dynamic<String> {
+        Self::generate_encoded(byte_len, crate::SecureConversionsExt::to_hex_secret)
+    }
+
+    /// Generate `byte_len` random bytes and return them as a protected URL-safe base64
+    /// string (no padding).
+    ///
+    /// The raw bytes are zeroized after encoding under `zeroize`.
+    ///
+    /// Panics if the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(all(feature = "rand", feature = "conversions"))]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let token = DynamicRng::generate_base64url(32);
+    /// assert_eq!(token.len(), 43);
+    /// # }
+    /// ```
+    #[cfg(all(feature = "rand", feature = "conversions"))]
+    pub fn generate_base64url(byte_len: usize) -> Dynamic<String> {
+        Self::generate_encoded(byte_len, crate::SecureConversionsExt::to_base64url_secret)
+    }
+
+    #[cfg(all(feature = "rand", feature = "conversions"))]
+    fn generate_encoded(byte_len: usize, encode: fn(&[u8]) -> Dynamic<String>) -> Dynamic<String> {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut raw = Self::generate(byte_len);
+        let encoded = encode(raw.expose_secret());
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(raw.0.expose_secret_mut());
+        encoded
+    }
+
     /// Expose the random bytes for read-only access.
     ///
     /// This is the **only** way to read the secret — loud and auditable.
@@ -217,14 +625,47 @@ impl DynamicRng {
     pub fn into_inner(self) -> Dynamic<Vec<u8>> {
         self.0
     }
+
+    /// Move the bytes into a stack `Fixed<[u8; N]>` if the length is exactly `N`.
+    ///
+    /// On success the heap buffer is zeroized (with `zeroize`) before it is freed.
+    /// On a length mismatch the original `DynamicRng` is returned untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::rng::DynamicRng;
+    /// let key = DynamicRng::generate(32).try_into_fixed::<32>().unwrap();
+    /// assert_eq!(key.len(), 32);
+    ///
+    /// let wrong = DynamicRng::generate(16).try_into_fixed::<32>();
+    /// assert_eq!(wrong.unwrap_err().len(), 16);
+    /// # }
+    /// ```
+    pub fn try_into_fixed<const N: usize>(
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))] mut self,
+    ) -> Result<Fixed<[u8; N]>, DynamicRng> {
+        if self.len() != N {
+            return Err(self);
+        }
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(self.0.expose_secret());
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(self.0.expose_secret_mut());
+        Ok(Fixed::new(bytes))
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl core::fmt::Debug for DynamicRng {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("[REDACTED]")
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<DynamicRng> for Dynamic<Vec<u8>> {
     /// Convert a `DynamicRng` to `Dynamic`, transferring ownership.
     ///
@@ -246,3 +687,261 @@ impl From<DynamicRng> for Dynamic<Vec<u8>> {
         rng.into_inner()
     }
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// EntropyPool — buffered OS entropy, fewer syscalls
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A 4 KiB buffer of `OsRng` output that serves many small requests per syscall.
+///
+/// Bytes are handed out in order and **wiped as soon as they are consumed**, so a later
+/// memory disclosure cannot recover values already issued (forward secrecy for past
+/// outputs). The unconsumed remainder *is* future output, though: anyone who can read
+/// the pool can predict the next values until they are drawn. Requests of
+/// [`CAPACITY`](Self::CAPACITY) bytes or more bypass the pool entirely.
+///
+/// After `fork`, parent and child hold the same pool and would issue identical values —
+/// call [`discard`](Self::discard) in the child. Unlike `SharedRng` there is no lock:
+/// the pool needs `&mut self`, so give each thread its own.
+///
+/// With `std`, `FixedRng::generate` and `DynamicRng::generate` already use a pool per
+/// thread; an owned `EntropyPool` is for `no_std` + `alloc` targets, or for keeping a
+/// pool's lifetime (and `discard`) under the caller's control.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "rand")]
+/// # {
+/// use secure_gate::rng::EntropyPool;
+/// let mut pool = EntropyPool::new();
+/// let tokens: Vec<_> = (0..1000).map(|_| pool.generate::<16>()).collect();
+/// assert_ne!(tokens[0].expose_secret(), tokens[1].expose_secret());
+/// # }
+/// ```
+#[cfg(all(feature = "rand", feature = "alloc"))]
+pub struct EntropyPool {
+    buf: alloc::boxed::Box<[u8; EntropyPool::CAPACITY]>,
+    // Bytes before `pos` are consumed (and wiped); bytes from `pos` on are unissued.
+    pos: usize,
+}
+
+#[cfg(all(feature = "rand", feature = "alloc"))]
+impl EntropyPool {
+    /// Size of the buffer, in bytes.
+    pub const CAPACITY: usize = 4096;
+
+    /// Create an empty pool. Nothing is read from the OS until the first request.
+    pub fn new() -> Self {
+        Self {
+            buf: alloc::boxed::Box::new([0u8; Self::CAPACITY]),
+            pos: Self::CAPACITY,
+        }
+    }
+
+    /// Generate a fixed-size random value from the pool.
+    ///
+    /// Panics if a refill is needed and the OS RNG fails — see
+    /// [`try_generate`](Self::try_generate).
+    pub fn generate<const N: usize>(&mut self) -> FixedRng<N> {
+        self.try_generate().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Generate a fixed-size random value, or return [`RNG_UNAVAILABLE`].
+    pub fn try_generate<const N: usize>(&mut self) -> Result<FixedRng<N>, &'static str> {
+        let mut bytes = [0u8; N];
+        self.fill(&mut bytes)?;
+        Ok(FixedRng(Fixed::new(bytes)))
+    }
+
+    /// Generate `len` random bytes on the heap from the pool.
+    ///
+    /// Panics if a refill is needed and the OS RNG fails — see
+    /// [`try_generate_dynamic`](Self::try_generate_dynamic).
+    pub fn generate_dynamic(&mut self, len: usize) -> DynamicRng {
+        self.try_generate_dynamic(len).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Generate `len` random bytes on the heap, or return [`RNG_UNAVAILABLE`].
+    pub fn try_generate_dynamic(&mut self, len: usize) -> Result<DynamicRng, &'static str> {
+        let mut bytes = vec![0u8; len];
+        self.fill(&mut bytes)?;
+        Ok(DynamicRng(Dynamic::new(bytes)))
+    }
+
+    /// Wipe every unissued byte, forcing the next request to refill from the OS.
+    ///
+    /// Call this in a child process after `fork`, or whenever buffered output may have
+    /// been observed.
+    pub fn discard(&mut self) {
+        wipe_bytes(&mut self.buf[self.pos..]);
+        self.pos = Self::CAPACITY;
+    }
+
+    /// Number of unissued bytes currently buffered — safe public metadata.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        Self::CAPACITY - self.pos
+    }
+
+    // On error, `out` (possibly part-written) is wiped before returning.
+    fn fill(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        let result = self.fill_inner(out);
+        if result.is_err() {
+            wipe_bytes(out);
+        }
+        result
+    }
+
+    fn fill_inner(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        if out.len() >= Self::CAPACITY {
+            return fill_os(out);
+        }
+        let mut written = 0;
+        while written < out.len() {
+            if self.pos == Self::CAPACITY {
+                // A failed refill may have written part of the buffer; it was never issued
+                if let Err(e) = fill_os(&mut self.buf[..]) {
+                    wipe_bytes(&mut self.buf[..]);
+                    return Err(e);
+                }
+                self.pos = 0;
+            }
+            let take = (out.len() - written).min(Self::CAPACITY - self.pos);
+            let src = &mut self.buf[self.pos..self.pos + take];
+            out[written..written + take].copy_from_slice(src);
+            wipe_bytes(src);
+            self.pos += take;
+            written += take;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+#[inline]
+fn wipe_bytes(bytes: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(bytes);
+    #[cfg(not(feature = "zeroize"))]
+    bytes.fill(0);
+}
+
+#[cfg(all(feature = "rand", feature = "alloc"))]
+impl Default for EntropyPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "alloc"))]
+impl Drop for EntropyPool {
+    fn drop(&mut self) {
+        self.discard();
+    }
+}
+
+#[cfg(all(feature = "rand", feature = "alloc"))]
+impl core::fmt::Debug for EntropyPool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SharedRng — one reseedable userspace generator for many threads
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `Send + Sync` ChaCha20 generator, seeded from `OsRng`, for sharing across threads.
+///
+/// See the [module docs](self#threads) for the tradeoffs against the default OS path.
+///
+/// Requires the `shared-rng` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "shared-rng")]
+/// # {
+/// use secure_gate::rng::SharedRng;
+/// use std::sync::Arc;
+///
+/// let rng = Arc::new(SharedRng::new());
+/// let worker = {
+///     let rng = Arc::clone(&rng);
+///     std::thread::spawn(move || rng.generate::<32>())
+/// };
+/// let token = worker.join().unwrap();
+/// assert_eq!(token.len(), 32);
+/// # }
+/// ```
+#[cfg(feature = "shared-rng")]
+pub struct SharedRng(std::sync::Mutex<rand_chacha::ChaCha20Rng>);
+
+#[cfg(feature = "shared-rng")]
+impl SharedRng {
+    /// Seed a new generator from the OS entropy source.
+    ///
+    /// Panics if the OS RNG is unavailable; see [`try_new`](Self::try_new).
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Seed a new generator from the OS entropy source, or return [`RNG_UNAVAILABLE`].
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(std::sync::Mutex::new(seed_chacha()?)))
+    }
+
+    /// Replace the generator state with a fresh seed from the OS entropy source.
+    ///
+    /// Panics if the OS RNG is unavailable.
+    pub fn reseed(&self) {
+        let fresh = seed_chacha().unwrap_or_else(|e| panic!("{e}"));
+        *self.lock() = fresh;
+    }
+
+    /// Generate a fixed-size random value.
+    pub fn generate<const N: usize>(&self) -> FixedRng<N> {
+        let mut bytes = [0u8; N];
+        self.fill(&mut bytes);
+        FixedRng(Fixed::new(bytes))
+    }
+
+    /// Generate a heap-allocated random value of `len` bytes.
+    pub fn generate_dynamic(&self, len: usize) -> DynamicRng {
+        let mut bytes = vec![0u8; len];
+        self.fill(&mut bytes);
+        DynamicRng(Dynamic::new(bytes))
+    }
+
+    #[inline]
+    fn fill(&self, bytes: &mut [u8]) {
+        rand::RngCore::fill_bytes(&mut *self.lock(), bytes);
+    }
+
+    // A panic elsewhere while holding the lock cannot leave ChaCha state invalid,
+    // so a poisoned mutex is still safe to use.
+    #[inline]
+    fn lock(&self) -> std::sync::MutexGuard<'_, rand_chacha::ChaCha20Rng> {
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(feature = "shared-rng")]
+fn seed_chacha() -> Result<rand_chacha::ChaCha20Rng, &'static str> {
+    rand::SeedableRng::try_from_rng(&mut OsRng).map_err(|_| RNG_UNAVAILABLE)
+}
+
+#[cfg(feature = "shared-rng")]
+impl Default for SharedRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "shared-rng")]
+impl core::fmt::Debug for SharedRng {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
@@ -0,0 +1,343 @@
+// ==========================================================================
+// src/serde.rs
+// ==========================================================================
+
+//! Opt-in `serde` support.
+//!
+//! `Fixed<[u8; N]>` (with `conversions` + `alloc`) serializes as a base64url string (no padding)
+//! when the format is human-readable (JSON, TOML, …) and as raw bytes otherwise
+//! (bincode, CBOR, …). Deserialization accepts the matching form and enforces the
+//! exact length `N`.
+//!
+//! `Dynamic<T>` (with `secrecy`) is serializable only when `T` opts in through
+//! `secrecy`'s [`SerializableSecret`](secrecy::SerializableSecret) marker — the same rule
+//! `secrecy::SecretBox<T>` follows, so one marker impl covers both crates.
+//!
+//! For a secret field that should appear in output only as a placeholder, use
+//! [`redact`] as a per-field `serialize_with`.
+//!
+//! `Dynamic<Vec<u8>>` (with `conversions` + `alloc`) has an authenticated path: [`Sealed`]
+//! writes the bytes with a BLAKE3 keyed-hash tag, and [`SecureDeserializeSeed`] only
+//! wraps input whose tag verifies under the same 32-byte key.
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+use crate::Fixed;
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+use core::fmt;
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+use ::serde::de::{self, Deserializer, SeqAccess, Visitor};
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+use ::serde::{Deserialize, Serialize, Serializer};
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl<const N: usize> Serialize for Fixed<[u8; N]> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            use crate::SecureConversionsExt;
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            let mut encoded = self.expose_secret().to_base64url();
+            let result = serializer.serialize_str(&encoded);
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut encoded);
+            result
+        } else {
+            serializer.serialize_bytes(self.expose_secret())
+        }
+    }
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+struct FixedVisitor<const N: usize>;
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl<'de, const N: usize> Visitor<'de> for FixedVisitor<N> {
+    type Value = Fixed<[u8; N]>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{N} bytes or a base64url string encoding {N} bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Fixed::from_base64url(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        if v.len() != N {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+        Ok(Fixed::from_slice(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut arr = [0u8; N];
+        for (i, slot) in arr.iter_mut().enumerate() {
+            *slot = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut arr);
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+        Ok(Fixed::new(arr))
+    }
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl<'de, const N: usize> Deserialize<'de> for Fixed<[u8; N]> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FixedVisitor::<N>)
+        } else {
+            deserializer.deserialize_bytes(FixedVisitor::<N>)
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Dynamic<T> — opt-in via secrecy's `SerializableSecret` marker
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Serializes the inner value transparently — only for `T: SerializableSecret`.
+///
+/// Unmarked types stay unserializable:
+///
+/// ```compile_fail
+/// let pw: secure_gate::Dynamic<String> = "hunter2".into();
+/// serde_json::to_string(&pw).unwrap(); // error: `String` is not `SerializableSecret`
+/// ```
+///
+/// The marker is foreign, so mark a local newtype rather than `String` / `Vec<u8>`:
+///
+/// ```
+/// # #[cfg(all(feature = "serde", feature = "secrecy"))]
+/// # {
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct ApiToken(String);
+/// impl secrecy::SerializableSecret for ApiToken {}
+///
+/// let token = secure_gate::Dynamic::new(ApiToken("t0k3n".into()));
+/// assert_eq!(serde_json::to_string(&token).unwrap(), r#""t0k3n""#);
+/// # }
+/// ```
+#[cfg(feature = "secrecy")]
+impl<T: secrecy::SerializableSecret> ::serde::Serialize for crate::Dynamic<T> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.expose_secret().serialize(serializer)
+    }
+}
+
+/// Deserializes straight into the heap wrapper — only for `T: SerializableSecret`, so
+/// exactly the types that can be written out can be read back.
+#[cfg(feature = "secrecy")]
+impl<'de, T> ::serde::Deserialize<'de> for crate::Dynamic<T>
+where
+    T: secrecy::SerializableSecret + ::serde::Deserialize<'de>,
+{
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(crate::Dynamic::new)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Per-field redaction
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `serialize_with` helper that writes the string `"[REDACTED]"` in place of the field.
+///
+/// Works for any field type, including secrets that have no `Serialize` impl at all.
+/// The marker is written with `Serializer::serialize_str`, never by formatting the value,
+/// so every format — JSON, CBOR, bincode, … — gets the same placeholder and none of the
+/// secret.
+/// Output-only: a redacted field cannot be read back, so pair it with `#[serde(skip)]`
+/// on the deserialize side (or don't derive `Deserialize`).
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use secure_gate::Dynamic;
+///
+/// #[derive(serde::Serialize)]
+/// struct Login {
+///     user: String,
+///     #[serde(serialize_with = "secure_gate::serde::redact")]
+///     password: Dynamic<String>,
+/// }
+///
+/// let login = Login { user: "alice".into(), password: "hunter2".into() };
+/// assert_eq!(
+///     serde_json::to_string(&login).unwrap(),
+///     r#"{"user":"alice","password":"[REDACTED]"}"#
+/// );
+/// # }
+/// ```
+pub fn redact<T: ?Sized, S: ::serde::Serializer>(
+    _secret: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str("[REDACTED]")
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Dynamic<Vec<u8>> — authenticated (sealed) round-trip
+// ─────────────────────────────────────────────────────────────────────────────
+//
+// Wire format: `payload || tag`, where `tag = BLAKE3-keyed(key, payload)` (32 bytes).
+// Human-readable formats carry it as one base64url string, binary formats as bytes —
+// the same split as `Fixed<[u8; N]>`. The payload is authenticated, not encrypted.
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+const TAG_LEN: usize = blake3::OUT_LEN;
+
+/// Serializes a `Dynamic<Vec<u8>>` together with an authentication tag under `key`.
+///
+/// The counterpart of [`SecureDeserializeSeed`]. The payload travels in the clear —
+/// sealing proves it was produced by a key holder and has not been altered.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(all(feature = "serde", feature = "conversions"))]
+/// # {
+/// use serde::de::DeserializeSeed;
+/// use secure_gate::serde::{Sealed, SecureDeserializeSeed};
+/// use secure_gate::{Dynamic, Fixed};
+///
+/// let key = Fixed::new([9u8; 32]);
+/// let secret: Dynamic<Vec<u8>> = vec![1, 2, 3].into();
+///
+/// let json = serde_json::to_string(&Sealed::new(&key, &secret)).unwrap();
+/// let mut de = serde_json::Deserializer::from_str(&json);
+/// let back = SecureDeserializeSeed(&key).deserialize(&mut de).unwrap();
+/// assert_eq!(back.expose_secret(), &[1, 2, 3]);
+/// # }
+/// ```
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+pub struct Sealed<'a> {
+    key: &'a Fixed<[u8; 32]>,
+    secret: &'a crate::Dynamic<alloc::vec::Vec<u8>>,
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl<'a> Sealed<'a> {
+    /// Pair `secret` with the key that will tag it.
+    #[inline]
+    pub fn new(key: &'a Fixed<[u8; 32]>, secret: &'a crate::Dynamic<alloc::vec::Vec<u8>>) -> Self {
+        Self { key, secret }
+    }
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl fmt::Debug for Sealed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl Serialize for Sealed<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let payload = self.secret.expose_secret();
+        let tag = blake3::keyed_hash(self.key.expose_secret(), payload);
+
+        let mut sealed = alloc::vec::Vec::with_capacity(payload.len() + TAG_LEN);
+        sealed.extend_from_slice(payload);
+        sealed.extend_from_slice(tag.as_bytes());
+
+        let result = if serializer.is_human_readable() {
+            use crate::SecureConversionsExt;
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            let mut encoded = sealed.to_base64url();
+            let result = serializer.serialize_str(&encoded);
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut encoded);
+            result
+        } else {
+            serializer.serialize_bytes(&sealed)
+        };
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut sealed);
+        result
+    }
+}
+
+/// Deserializes a `Dynamic<Vec<u8>>` only if its authentication tag verifies under the
+/// given key.
+///
+/// Use with [`serde::de::DeserializeSeed`] and input
+/// written by [`Sealed`]. The tag is checked in constant time; on a mismatch (or input
+/// shorter than a tag) the received bytes are wiped under `zeroize` and an error is
+/// returned, so unauthenticated data never becomes a `Dynamic`.
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+pub struct SecureDeserializeSeed<'k>(pub &'k Fixed<[u8; 32]>);
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl fmt::Debug for SecureDeserializeSeed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl<'de> de::DeserializeSeed<'de> for SecureDeserializeSeed<'_> {
+    type Value = crate::Dynamic<alloc::vec::Vec<u8>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let mut sealed = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(SealedVisitor)?
+        } else {
+            deserializer.deserialize_bytes(SealedVisitor)?
+        };
+
+        let verified = sealed.len() >= TAG_LEN && {
+            let (payload, tag) = sealed.split_at(sealed.len() - TAG_LEN);
+            let mut received = [0u8; TAG_LEN];
+            received.copy_from_slice(tag);
+            // `blake3::Hash`'s `PartialEq` is constant-time
+            blake3::keyed_hash(self.0.expose_secret(), payload) == blake3::Hash::from(received)
+        };
+        if !verified {
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut sealed);
+            return Err(de::Error::custom("sealed secret failed authentication"));
+        }
+
+        sealed.truncate(sealed.len() - TAG_LEN);
+        Ok(crate::Dynamic::new(sealed))
+    }
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+struct SealedVisitor;
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+impl<'de> Visitor<'de> for SealedVisitor {
+    type Value = alloc::vec::Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sealed bytes or a base64url string of sealed bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        use base64::Engine;
+        base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(v)
+            .map_err(|_| E::custom("invalid base64url string"))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = alloc::vec::Vec::new();
+        while let Some(b) = seq.next_element()? {
+            crate::dynamic::secure_reserve_vec(&mut bytes, 1);
+            bytes.push(b);
+        }
+        Ok(bytes)
+    }
+}
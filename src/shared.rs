@@ -0,0 +1,106 @@
+// ==========================================================================
+// src/shared.rs
+// ==========================================================================
+
+//! Read-only secrets shared through one reference-counted allocation.
+//!
+//! Cloning a [`Dynamic`](crate::Dynamic) duplicates the secret. [`SharedSecret`] instead
+//! shares a single `Arc` allocation between clones, so handing a key to several tasks
+//! leaves exactly one copy in memory.
+//!
+//! With `zeroize`, the wipe lives in the drop of the `Arc`'s contents rather than in each
+//! handle, so it runs exactly once — when the last clone goes away — no matter which
+//! thread drops it. (Checking `Arc::strong_count` in each handle's drop would race: two
+//! handles dropped concurrently could both see a count of 2 and neither would wipe.)
+
+use alloc::sync::Arc;
+use core::fmt;
+
+struct Inner<T> {
+    value: T,
+    #[cfg(feature = "zeroize")]
+    wipe: fn(&mut T),
+}
+
+#[cfg(feature = "zeroize")]
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        (self.wipe)(&mut self.value);
+    }
+}
+
+/// A read-only secret shared between cheap clones.
+///
+/// `Clone` bumps a reference count; it never copies the secret. There is no mutable
+/// access. `Debug` is always redacted.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::SharedSecret;
+/// let key = SharedSecret::new(vec![0xAAu8; 32]);
+/// let for_task = key.clone();
+/// assert!(SharedSecret::ptr_eq(&key, &for_task));
+/// assert_eq!(for_task.expose_secret(), &vec![0xAAu8; 32]);
+/// ```
+pub struct SharedSecret<T>(Arc<Inner<T>>);
+
+#[cfg(feature = "zeroize")]
+impl<T: zeroize::Zeroize> SharedSecret<T> {
+    /// Wrap `value` in a new shared allocation.
+    ///
+    /// The value is zeroized when the last clone is dropped.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(Inner {
+            value,
+            wipe: zeroize::Zeroize::zeroize,
+        }))
+    }
+}
+
+#[cfg(not(feature = "zeroize"))]
+impl<T> SharedSecret<T> {
+    /// Wrap `value` in a new shared allocation.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(Inner { value }))
+    }
+}
+
+impl<T> SharedSecret<T> {
+    /// Expose the secret for read-only access.
+    ///
+    /// With `audit`, this notifies the registered exposure hook like `Dynamic::expose_secret`.
+    #[inline]
+    pub fn expose_secret(&self) -> &T {
+        #[cfg(feature = "audit")]
+        crate::audit::on_expose::<T>();
+        &self.0.value
+    }
+
+    /// Returns `true` if both handles share the same allocation.
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+
+    /// Number of live handles to this allocation — safe public metadata.
+    #[inline]
+    pub fn handle_count(this: &Self) -> usize {
+        Arc::strong_count(&this.0)
+    }
+}
+
+impl<T> Clone for SharedSecret<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> fmt::Debug for SharedSecret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
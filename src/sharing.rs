@@ -0,0 +1,73 @@
+// ==========================================================================
+// src/sharing.rs
+// ==========================================================================
+
+//! XOR (n-of-n) secret sharing for `Dynamic<Vec<u8>>`.
+//!
+//! [`split_xor_shares`](crate::Dynamic::split_xor_shares) produces `n` shares whose XOR is
+//! the secret: `n - 1` are fresh `OsRng` output and the last is the secret XORed with all
+//! of them. Any `n - 1` shares are uniformly random and independent of the secret; all
+//! `n` are needed to rebuild it. There is no threshold — lose one share and the secret
+//! is gone. Shares are ordinary `Dynamic<Vec<u8>>` values with the same protections.
+
+use crate::Dynamic;
+use alloc::vec::Vec;
+
+impl Dynamic<Vec<u8>> {
+    /// Split the secret into `n` shares that XOR together to the original.
+    ///
+    /// Every share has the secret's length. Panics if `n == 0` or the RNG fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use secure_gate::Dynamic;
+    /// let secret: Dynamic<Vec<u8>> = vec![1, 2, 3, 4].into();
+    /// let shares = secret.split_xor_shares(3);
+    /// assert_eq!(shares.len(), 3);
+    ///
+    /// let rebuilt = Dynamic::<Vec<u8>>::combine_xor_shares(&shares);
+    /// assert_eq!(rebuilt.expose_secret(), secret.expose_secret());
+    /// # }
+    /// ```
+    pub fn split_xor_shares(&self, n: usize) -> Vec<Dynamic<Vec<u8>>> {
+        assert!(n > 0, "at least one share is required");
+        let len = self.len();
+
+        let mut shares: Vec<Dynamic<Vec<u8>>> = (1..n)
+            .map(|_| crate::rng::DynamicRng::generate(len).into_inner())
+            .collect();
+        let mut last = self.expose_secret().to_vec();
+        for share in &shares {
+            xor_into(&mut last, share.expose_secret());
+        }
+        shares.push(Dynamic::new(last));
+        shares
+    }
+
+    /// Rebuild a secret by XORing all of its shares together.
+    ///
+    /// Panics if `shares` is empty or the shares differ in length. Combining an
+    /// incomplete set yields random bytes, not an error — XOR sharing carries no
+    /// integrity check.
+    pub fn combine_xor_shares(shares: &[Dynamic<Vec<u8>>]) -> Dynamic<Vec<u8>> {
+        let (first, rest) = shares
+            .split_first()
+            .expect("at least one share is required");
+        let mut secret = first.expose_secret().to_vec();
+        for share in rest {
+            assert_eq!(share.len(), secret.len(), "shares must have equal lengths");
+            xor_into(&mut secret, share.expose_secret());
+        }
+        Dynamic::new(secret)
+    }
+}
+
+#[inline]
+fn xor_into(acc: &mut [u8], share: &[u8]) {
+    for (a, s) in acc.iter_mut().zip(share) {
+        *a ^= s;
+    }
+}
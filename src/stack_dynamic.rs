@@ -0,0 +1,205 @@
+// ==========================================================================
+// src/stack_dynamic.rs
+// ==========================================================================
+
+//! Variable-length byte secrets with a fixed capacity, stored inline.
+//!
+//! [`StackDynamic<CAP>`] sits between [`Fixed`](crate::Fixed) (exact size, known at
+//! compile time) and [`Dynamic`](crate::Dynamic) (any size, on the heap): it holds up to
+//! `CAP` bytes in a `[u8; CAP]` plus a length, so it needs no allocator — for passwords
+//! or tokens of bounded length in `no_std` code.
+//!
+//! Bytes past the current length are always zero: shrinking wipes them, and growth past
+//! `CAP` is rejected without modifying the secret.
+
+use core::fmt;
+
+/// A secret of up to `CAP` bytes, stored inline without allocation.
+///
+/// Only the first [`len`](Self::len) bytes are exposed. `Debug` is always redacted, and
+/// with `zeroize` the whole buffer is wiped on drop.
+///
+/// # Example
+///
+/// ```
+/// use secure_gate::StackDynamic;
+/// let mut pin = StackDynamic::<8>::new();
+/// pin.extend_from_slice(b"1234")?;
+/// pin.push(b'5')?;
+/// assert_eq!(pin.expose_secret(), b"12345");
+/// assert!(pin.extend_from_slice(b"6789").is_err()); // would exceed CAP
+/// assert_eq!(format!("{pin:?}"), "[REDACTED]");
+/// # Ok::<(), &'static str>(())
+/// ```
+pub struct StackDynamic<const CAP: usize> {
+    buf: [u8; CAP],
+    len: usize,
+}
+
+const CAPACITY_EXCEEDED: &str = "StackDynamic capacity exceeded";
+
+impl<const CAP: usize> StackDynamic<CAP> {
+    /// The maximum number of bytes this secret can hold.
+    pub const CAPACITY: usize = CAP;
+
+    /// Create an empty secret.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            buf: [0u8; CAP],
+            len: 0,
+        }
+    }
+
+    /// Copy `bytes` into a new secret, or fail if they do not fit in `CAP`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use secure_gate::StackDynamic;
+    /// let token = StackDynamic::<16>::try_from_slice(b"tok")?;
+    /// assert_eq!(token.len(), 3);
+    /// assert!(StackDynamic::<2>::try_from_slice(b"tok").is_err());
+    /// # Ok::<(), &'static str>(())
+    /// ```
+    #[inline]
+    pub fn try_from_slice(bytes: &[u8]) -> Result<Self, &'static str> {
+        let mut secret = Self::new();
+        secret.extend_from_slice(bytes)?;
+        Ok(secret)
+    }
+
+    /// Expose the current contents for read-only access.
+    ///
+    /// This is the **only** way to read the secret — loud and auditable.
+    #[inline(always)]
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Expose the current contents for mutable access (the length cannot change).
+    ///
+    /// This is the **only** way to mutate the secret in place — loud and auditable.
+    #[inline(always)]
+    pub fn expose_secret_mut(&mut self) -> &mut [u8] {
+        &mut self.buf[..self.len]
+    }
+
+    /// Append one byte, or fail if the secret is full (leaving it unchanged).
+    #[inline]
+    pub fn push(&mut self, byte: u8) -> Result<(), &'static str> {
+        if self.len == CAP {
+            return Err(CAPACITY_EXCEEDED);
+        }
+        self.buf[self.len] = byte;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Append `bytes`, or fail if they do not all fit (leaving the secret unchanged).
+    #[inline]
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) -> Result<(), &'static str> {
+        if bytes.len() > self.remaining() {
+            return Err(CAPACITY_EXCEEDED);
+        }
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+
+    /// Shorten the secret to `len` bytes, wiping the vacated tail. No-op if `len` is not
+    /// shorter than the current length.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            wipe(&mut self.buf[len..self.len]);
+            self.len = len;
+        }
+    }
+
+    /// Remove and wipe all bytes.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns the current length in bytes.
+    ///
+    /// This is safe public metadata — does not expose the secret.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the secret holds no bytes.
+    ///
+    /// This is safe public metadata — does not expose the secret.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the capacity, `CAP`.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns how many more bytes fit before the capacity is reached.
+    #[inline(always)]
+    pub const fn remaining(&self) -> usize {
+        CAP - self.len
+    }
+}
+
+// Volatile under `zeroize`; a plain fill otherwise, which still keeps the bytes past
+// `len` zero for the live buffer.
+#[inline]
+fn wipe(bytes: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(bytes);
+    #[cfg(not(feature = "zeroize"))]
+    bytes.fill(0);
+}
+
+impl<const CAP: usize> Default for StackDynamic<CAP> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Explicit Clone only — no implicit Copy
+impl<const CAP: usize> Clone for StackDynamic<CAP> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf,
+            len: self.len,
+        }
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for StackDynamic<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const CAP: usize> zeroize::Zeroize for StackDynamic<CAP> {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.buf);
+        self.len = 0;
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const CAP: usize> zeroize::ZeroizeOnDrop for StackDynamic<CAP> {}
+
+#[cfg(feature = "zeroize")]
+impl<const CAP: usize> Drop for StackDynamic<CAP> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.buf);
+    }
+}
@@ -0,0 +1,56 @@
+// ==========================================================================
+// src/traits.rs
+// ==========================================================================
+
+//! Shared traits across the wrapper families.
+
+use crate::Fixed;
+#[cfg(feature = "alloc")]
+use crate::Dynamic;
+
+/// Explicitly named duplication of a secret, for generic code over any cloneable wrapper.
+///
+/// `clone_secret` is `Clone::clone` under a name that stands out in review — duplicating
+/// a secret should be a visible decision. Implemented for `Fixed<T>` and `Dynamic<T>`
+/// whenever they are `Clone` (with `zeroize`, `Dynamic<T>` also needs `T: Zeroize`).
+/// The `*NoClone` wrappers deliberately do not implement it.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use secure_gate::{CloneSecret, Dynamic, Fixed};
+///
+/// fn backup<S: CloneSecret>(secret: &S) -> S {
+///     secret.clone_secret()
+/// }
+///
+/// let key = Fixed::new([1u8; 32]);
+/// let pw: Dynamic<String> = "hunter2".into();
+/// assert_eq!(backup(&key).expose_secret(), key.expose_secret());
+/// assert_eq!(backup(&pw).expose_secret(), "hunter2");
+/// # }
+/// ```
+pub trait CloneSecret: Sized {
+    /// Return an independent copy of the secret.
+    fn clone_secret(&self) -> Self;
+}
+
+impl<T: Clone> CloneSecret for Fixed<T> {
+    #[inline(always)]
+    fn clone_secret(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> CloneSecret for Dynamic<T>
+where
+    Dynamic<T>: Clone,
+{
+    #[inline]
+    fn clone_secret(&self) -> Self {
+        self.clone()
+    }
+}
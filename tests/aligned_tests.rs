@@ -0,0 +1,72 @@
+// ==========================================================================
+// tests/aligned_tests.rs
+// ==========================================================================
+// Testing for alignment-guaranteed fixed secrets
+
+use secure_gate::aligned::{Align16, Align32, Align64};
+use secure_gate::AlignedFixed;
+
+// ──────────────────────────────────────────────────────────────
+// Alignment guarantees
+// ──────────────────────────────────────────────────────────────
+
+fn addr<const N: usize, A: secure_gate::aligned::Alignment>(k: &AlignedFixed<N, A>) -> usize {
+    k.expose_secret().as_ptr() as usize
+}
+
+#[test]
+fn aligned_fixed_pointer_is_aligned() {
+    let k16 = AlignedFixed::<32, Align16>::new([1; 32]);
+    let k32 = AlignedFixed::<32, Align32>::new([2; 32]);
+    let k64 = AlignedFixed::<64, Align64>::new([3; 64]);
+    assert_eq!(addr(&k16) % 16, 0);
+    assert_eq!(addr(&k32) % 32, 0);
+    assert_eq!(addr(&k64) % 64, 0);
+
+    // Holds in arrays and on the heap too
+    let many = [
+        AlignedFixed::<17, Align32>::new([0; 17]),
+        AlignedFixed::<17, Align32>::new([0; 17]),
+    ];
+    for k in &many {
+        assert_eq!(addr(k) % 32, 0);
+    }
+    let boxed = Box::new(AlignedFixed::<8, Align64>::new([0; 8]));
+    assert_eq!(addr(&boxed) % 64, 0);
+}
+
+#[test]
+fn aligned_fixed_layout() {
+    assert_eq!(core::mem::align_of::<AlignedFixed<32, Align32>>(), 32);
+    assert_eq!(core::mem::size_of::<AlignedFixed<32, Align32>>(), 32); // no padding
+    assert_eq!(core::mem::size_of::<AlignedFixed<33, Align32>>(), 64);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Access, Debug, Clone, zeroize
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn aligned_fixed_access_and_redaction() {
+    let mut key: AlignedFixed<4, Align16> = [1, 2, 3, 4].into();
+    assert_eq!(key.len(), 4);
+    assert!(!key.is_empty());
+    key.expose_secret_mut()[0] = 9;
+    assert_eq!(key.expose_secret(), &[9, 2, 3, 4]);
+    assert_eq!(format!("{key:?}"), "[REDACTED]");
+
+    let copy = key.clone();
+    assert_eq!(copy.expose_secret(), key.expose_secret());
+    assert_eq!(addr(&copy) % 16, 0);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn aligned_fixed_zeroize() {
+    fn assert_zod<T: zeroize::ZeroizeOnDrop>() {}
+    assert_zod::<AlignedFixed<32, Align32>>();
+
+    let mut key = AlignedFixed::<32, Align32>::new([0xAA; 32]);
+    key.zeroize_now();
+    assert_eq!(key.expose_secret(), &[0u8; 32]);
+}
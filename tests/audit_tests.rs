@@ -0,0 +1,80 @@
+// ==========================================================================
+// tests/audit_tests.rs
+// ==========================================================================
+// Testing for the global exposure hook
+//
+// The hook is process-global, so this file is its own test binary and keeps
+// everything in a single test to avoid cross-test interference.
+
+#![cfg(feature = "audit")]
+
+use secure_gate::{audit, Dynamic};
+use std::sync::Mutex;
+
+static LABELS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+fn record(label: &'static str) {
+    LABELS.lock().unwrap().push(label);
+}
+
+fn take_labels() -> Vec<&'static str> {
+    core::mem::take(&mut *LABELS.lock().unwrap())
+}
+
+// A hook that itself exposes a secret must not deadlock
+fn reentrant(label: &'static str) {
+    record(label);
+    if label.contains("String") {
+        let inner: Dynamic<Vec<u8>> = Dynamic::new(vec![1u8]);
+        let _ = inner.expose_secret();
+    }
+}
+
+#[test]
+fn expose_hook_sees_type_labels_only() {
+    let mut key = Dynamic::<Vec<u8>>::new(vec![0xAA; 4]);
+    let pw: Dynamic<String> = "hunter2".into();
+
+    // No hook registered: nothing recorded
+    let _ = key.expose_secret();
+    assert!(take_labels().is_empty());
+
+    audit::set_expose_hook(record);
+    let _ = key.expose_secret();
+    key.expose_secret_mut()[0] = 0;
+    let _ = pw.expose_secret();
+    assert_eq!(
+        take_labels(),
+        [
+            "alloc::vec::Vec<u8>",
+            "alloc::vec::Vec<u8>",
+            "alloc::string::String"
+        ]
+    );
+
+    // Guards and pinning hand out the secret too
+    drop(key.guard());
+    drop(key.guard_with(|_| {}));
+    let pinned = Dynamic::<Vec<u8>>::new(vec![1u8]).into_pinned();
+    assert_eq!(
+        take_labels(),
+        [
+            "alloc::vec::Vec<u8>",
+            "alloc::vec::Vec<u8>",
+            "alloc::vec::Vec<u8>"
+        ]
+    );
+    drop(pinned);
+
+    // Metadata accessors are not exposures
+    let _ = (key.len(), pw.is_empty(), format!("{pw:?}"));
+    assert!(take_labels().is_empty());
+
+    audit::set_expose_hook(reentrant);
+    let _ = pw.expose_secret();
+    assert_eq!(take_labels(), ["alloc::string::String", "alloc::vec::Vec<u8>"]);
+
+    audit::clear_expose_hook();
+    let _ = pw.expose_secret();
+    assert!(take_labels().is_empty());
+}
@@ -0,0 +1,83 @@
+// ==========================================================================
+// tests/builder_tests.rs
+// ==========================================================================
+// Testing for build-then-freeze secret assembly
+
+#![cfg(feature = "alloc")]
+
+use secure_gate::{DynamicNoClone, SecretBuilder};
+
+// ──────────────────────────────────────────────────────────────
+// Building
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn builder_bytes_push_and_extend() {
+    let mut b = SecretBuilder::<Vec<u8>>::new();
+    assert!(b.is_empty());
+    b.extend_from_slice(b"key-");
+    for i in 0..=255u8 {
+        b.push(i);
+    }
+    assert_eq!(b.len(), 4 + 256);
+    assert_eq!(format!("{b:?}"), "[REDACTED]");
+
+    let secret = b.finish();
+    assert_eq!(&secret.expose_secret()[..4], b"key-");
+    assert_eq!(secret.expose_secret()[4..], (0..=255u8).collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn builder_string_push_and_push_str() {
+    let mut b = SecretBuilder::<String>::with_capacity(4);
+    b.push_str("pässwörd");
+    b.push('!');
+    assert_eq!(b.len(), "pässwörd!".len());
+
+    let secret: DynamicNoClone<String> = b.finish();
+    assert_eq!(secret.expose_secret(), "pässwörd!");
+}
+
+#[test]
+fn builder_with_capacity_avoids_growth() {
+    let mut b = SecretBuilder::<Vec<u8>>::with_capacity(64);
+    b.extend_from_slice(&[0xAA; 64]);
+    let secret = b.finish();
+    assert_eq!(secret.expose_secret(), &[0xAA; 64]);
+    assert_eq!(secret.expose_secret().capacity(), 64);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Finalizing
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn builder_finish_shrinks_capacity() {
+    let mut b = SecretBuilder::<Vec<u8>>::with_capacity(128);
+    b.extend_from_slice(b"short");
+    let secret = b.finish();
+    assert_eq!(secret.expose_secret(), b"short");
+    assert_eq!(secret.expose_secret().capacity(), 5);
+
+    let mut s = SecretBuilder::<String>::with_capacity(128);
+    s.push_str("abc");
+    assert_eq!(s.finish().expose_secret().capacity(), 3);
+}
+
+#[test]
+fn builder_finish_empty_and_default() {
+    let secret = SecretBuilder::<Vec<u8>>::default().finish();
+    assert!(secret.expose_secret().is_empty());
+
+    let secret = SecretBuilder::<String>::new().finish();
+    assert!(secret.expose_secret().is_empty());
+}
+
+#[test]
+fn builder_result_is_no_clone() {
+    let mut b = SecretBuilder::<Vec<u8>>::new();
+    b.push(1);
+    let secret = b.finish();
+    assert_eq!(format!("{secret:?}"), "[REDACTED]");
+    // secret.clone(); // compile error — correct (also checked by a compile_fail doctest)
+}
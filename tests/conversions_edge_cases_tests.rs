@@ -3,12 +3,15 @@
 // ==========================================================================
 // Comprehensive testing for conversions functionality
 
-#![cfg(feature = "conversions")]
+#![cfg(all(feature = "conversions", feature = "alloc"))]
 
-use secure_gate::{dynamic_alias, fixed_alias, HexString, RandomHex, SecureConversionsExt};
+use secure_gate::{dynamic_alias, fixed_alias, HexString, SecureConversionsExt};
 
 #[cfg(feature = "rand")]
-use secure_gate::{Dynamic, Fixed, rng::{DynamicRng, FixedRng}};
+use secure_gate::{
+    rng::{DynamicRng, FixedRng},
+    Dynamic, Fixed, RandomHex,
+};
 
 // ──────────────────────────────────────────────────────────────
 // Basic conversions functionality
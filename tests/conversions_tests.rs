@@ -3,12 +3,15 @@
 // ==========================================================================
 // Comprehensive testing for conversions functionality
 
-#![cfg(feature = "conversions")]
+#![cfg(all(feature = "conversions", feature = "alloc"))]
 
-use secure_gate::{dynamic_alias, fixed_alias, HexString, RandomHex, SecureConversionsExt};
+use secure_gate::{dynamic_alias, fixed_alias, Fixed, HexString, SecureConversionsExt};
 
 #[cfg(feature = "rand")]
-use secure_gate::{Dynamic, Fixed, rng::{DynamicRng, FixedRng}};
+use secure_gate::{
+    rng::{DynamicRng, FixedRng},
+    Dynamic, RandomHex,
+};
 
 // ──────────────────────────────────────────────────────────────
 // Basic conversions functionality
@@ -698,3 +701,426 @@ fn fixed_array_ct_eq() {
     assert!(!k1.expose_secret().ct_eq(k3.expose_secret()));
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// Allocation-free primitives: write_hex / ct_is_zero
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn write_hex_into_exact_buffer() {
+    let mut buf = [0u8; 8];
+    let hex = [0xde, 0xad, 0xbe, 0xef].write_hex(&mut buf).unwrap();
+    assert_eq!(hex, "deadbeef");
+}
+
+#[test]
+fn write_hex_into_larger_buffer_uses_prefix() {
+    let mut buf = [b'x'; 10];
+    let bytes: &[u8] = &[0x01, 0xab];
+    assert_eq!(bytes.write_hex(&mut buf).unwrap(), "01ab");
+    assert_eq!(&buf[4..], b"xxxxxx");
+}
+
+#[test]
+fn write_hex_buffer_too_small() {
+    let mut buf = [0u8; 7];
+    let err = [0u8; 4].write_hex(&mut buf).unwrap_err();
+    assert_eq!(err, "hex output buffer too small");
+}
+
+#[test]
+fn write_hex_matches_to_hex() {
+    let key = [0x42u8; 32];
+    let mut buf = [0u8; 64];
+    assert_eq!(key.write_hex(&mut buf).unwrap(), key.to_hex());
+}
+
+#[test]
+fn ct_is_zero_detects_any_nonzero_byte() {
+    assert!([0u8; 32].ct_is_zero());
+    assert!([0u8; 0].ct_is_zero());
+
+    let mut key = [0u8; 32];
+    key[31] = 1;
+    assert!(!key.ct_is_zero());
+    assert!(!key.as_slice().ct_is_zero());
+    assert!([0u8; 32].as_slice().ct_is_zero());
+}
+
+// ──────────────────────────────────────────────────────────────
+// Encodings wrapped as secrets: to_hex_secret / to_base64url_secret
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn to_hex_secret_matches_to_hex_and_is_redacted() {
+    let key = [0x42u8; 16];
+    let hex = key.to_hex_secret();
+    assert_eq!(hex.expose_secret(), &key.to_hex());
+    assert_eq!(format!("{hex:?}"), "[REDACTED]");
+
+    let slice: &[u8] = &[0xAB, 0xCD];
+    assert_eq!(slice.to_hex_secret().expose_secret(), "abcd");
+}
+
+#[test]
+fn to_base64url_secret_matches_to_base64url_and_is_redacted() {
+    let key = [0xFBu8, 0xFF];
+    let b64 = key.to_base64url_secret();
+    assert_eq!(b64.expose_secret(), "-_8");
+    assert_eq!(b64.expose_secret(), &key.to_base64url());
+    assert_eq!(format!("{b64:?}"), "[REDACTED]");
+}
+
+#[test]
+fn encoded_secret_empty_input() {
+    assert!([0u8; 0].to_hex_secret().is_empty());
+    assert!([0u8; 0].to_base64url_secret().is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// Protected decoding: from_base64url_secret / from_hex_secret
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn from_base64url_secret_roundtrip() {
+    let raw: Vec<u8> = (0u8..=255).collect();
+    let encoded = raw.as_slice().to_base64url_secret();
+    let decoded = secure_gate::Dynamic::<Vec<u8>>::from_base64url_secret(&encoded).unwrap();
+    assert_eq!(decoded.expose_secret(), &raw);
+    // Sized from the length estimate up front — never more than a few spare bytes
+    assert!(decoded.expose_secret().capacity() - decoded.len() < 4);
+}
+
+#[test]
+fn from_base64url_secret_rejects_invalid() {
+    let bad: secure_gate::Dynamic<String> = "not*base64!".into();
+    let err = secure_gate::Dynamic::<Vec<u8>>::from_base64url_secret(&bad).unwrap_err();
+    assert_eq!(err, "invalid base64url string");
+
+    let padded: secure_gate::Dynamic<String> = "3q2-7w==".into(); // padding not accepted
+    assert!(secure_gate::Dynamic::<Vec<u8>>::from_base64url_secret(&padded).is_err());
+}
+
+#[test]
+fn from_hex_secret_roundtrip_and_errors() {
+    let encoded = [0xde, 0xad, 0xbe, 0xef].to_hex_secret();
+    let decoded = secure_gate::Dynamic::<Vec<u8>>::from_hex_secret(&encoded).unwrap();
+    assert_eq!(decoded.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+
+    let upper: secure_gate::Dynamic<String> = "DEADBEEF".into();
+    assert!(secure_gate::Dynamic::<Vec<u8>>::from_hex_secret(&upper).is_ok());
+
+    for bad in ["abc", "zz", "12 4"] {
+        let input: secure_gate::Dynamic<String> = bad.into();
+        let err = secure_gate::Dynamic::<Vec<u8>>::from_hex_secret(&input).unwrap_err();
+        assert_eq!(err, "invalid hex string");
+    }
+}
+
+#[test]
+fn protected_decode_empty_input() {
+    let empty: secure_gate::Dynamic<String> = "".into();
+    assert!(secure_gate::Dynamic::<Vec<u8>>::from_base64url_secret(&empty).unwrap().is_empty());
+    assert!(secure_gate::Dynamic::<Vec<u8>>::from_hex_secret(&empty).unwrap().is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// Big-endian integer views: u128 / u64
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_u128_be_roundtrip() {
+    for v in [0u128, 1, 0xFF, 1 << 64, u128::MAX, 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210] {
+        let key = secure_gate::Fixed::<[u8; 16]>::from_u128_be(v);
+        assert_eq!(key.to_u128_be(), v);
+    }
+}
+
+#[test]
+fn fixed_u128_be_byte_order() {
+    let key = secure_gate::Fixed::<[u8; 16]>::from_u128_be(0x0102);
+    let mut expected = [0u8; 16];
+    expected[14] = 0x01;
+    expected[15] = 0x02;
+    assert_eq!(key.expose_secret(), &expected);
+}
+
+#[test]
+fn fixed_u64_be_roundtrip_and_order() {
+    for v in [0u64, 1, u64::MAX, 0xdead_beef_cafe_f00d] {
+        assert_eq!(secure_gate::Fixed::<[u8; 8]>::from_u64_be(v).to_u64_be(), v);
+    }
+    let key = secure_gate::Fixed::new([0x01, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(key.to_u64_be(), 1 << 56);
+}
+
+// ──────────────────────────────────────────────────────────────
+// byte_as_mask — branchless secret-byte masks
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_byte_as_mask_every_value() {
+    for b in 0u8..=255 {
+        let key = secure_gate::Fixed::new([b]);
+        let expected = if b == 0 { 0x00 } else { 0xFF };
+        assert_eq!(key.byte_as_mask(0), expected, "byte {b:#04x}");
+    }
+}
+
+#[test]
+fn fixed_byte_as_mask_selects_public_values() {
+    let secret_bits = secure_gate::Fixed::new([0u8, 3, 0, 1]);
+    let picked: Vec<u8> = (0..4)
+        .map(|i| {
+            let m = secret_bits.byte_as_mask(i);
+            (b'y' & m) | (b'n' & !m)
+        })
+        .collect();
+    assert_eq!(picked, b"nyny");
+}
+
+#[test]
+#[should_panic]
+fn fixed_byte_as_mask_out_of_range_panics() {
+    let _ = secure_gate::Fixed::new([1u8; 4]).byte_as_mask(4);
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_lookup — constant-time table lookup
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn ct_lookup_matches_direct_indexing() {
+    use secure_gate::conversions::ct_lookup;
+
+    let table: [u8; 256] = core::array::from_fn(|i| (i as u8).wrapping_mul(167).wrapping_add(13));
+    for index in 0u8..=255 {
+        assert_eq!(ct_lookup(&table, index), table[index as usize], "index {index}");
+    }
+
+    let identity: [u8; 256] = core::array::from_fn(|i| i as u8);
+    assert_eq!(ct_lookup(&identity, 0), 0);
+    assert_eq!(ct_lookup(&identity, 255), 255);
+}
+
+// ──────────────────────────────────────────────────────────────
+// gf128_mul — GHASH field multiplication
+// ──────────────────────────────────────────────────────────────
+
+fn block(hex_str: &str) -> secure_gate::Fixed<[u8; 16]> {
+    secure_gate::Fixed::from_hex(hex_str).unwrap()
+}
+
+// GCM spec test case 2 (K = 0^128, P = 0^128): H = E(K, 0^128), C = E(K, Y1).
+#[test]
+fn gf128_mul_gcm_test_case_2() {
+    let h = block("66e94bd4ef8a2c3b884cfa59ca342b2e");
+    let c = block("0388dace60b6a392f328c2b971b2fe78");
+
+    // X1 = C · H
+    let x1 = c.gf128_mul(&h);
+    assert_eq!(
+        x1.expose_secret(),
+        block("5e2ec746917062882c85b0685353deb7").expose_secret()
+    );
+
+    // X2 = (X1 ⊕ len(A) || len(C)) · H = GHASH(H, {}, C)
+    let mut lens = [0u8; 16];
+    lens[15] = 0x80; // len(C) = 128 bits
+    let mut x = *x1.expose_secret();
+    for (a, b) in x.iter_mut().zip(lens) {
+        *a ^= b;
+    }
+    let ghash = secure_gate::Fixed::new(x).gf128_mul(&h);
+    assert_eq!(
+        ghash.expose_secret(),
+        block("f38cbb1ad69223dcc3457ae5b6b0f885").expose_secret()
+    );
+}
+
+#[test]
+fn gf128_mul_identity_zero_and_commutativity() {
+    let a = block("66e94bd4ef8a2c3b884cfa59ca342b2e");
+    let b = block("0388dace60b6a392f328c2b971b2fe78");
+    let one = block("80000000000000000000000000000000");
+    let zero = secure_gate::Fixed::new([0u8; 16]);
+
+    assert_eq!(a.gf128_mul(&one).expose_secret(), a.expose_secret());
+    assert_eq!(one.gf128_mul(&a).expose_secret(), a.expose_secret());
+    assert_eq!(a.gf128_mul(&zero).expose_secret(), &[0u8; 16]);
+    assert_eq!(
+        a.gf128_mul(&b).expose_secret(),
+        b.gf128_mul(&a).expose_secret()
+    );
+}
+
+#[test]
+fn gf128_mul_reduces_top_coefficient() {
+    // x^127 · x = x^128 ≡ x^7 + x^2 + x + 1 → reflected: 0xE1 in byte 0
+    let x127 = block("00000000000000000000000000000001");
+    let x = block("40000000000000000000000000000000");
+    assert_eq!(
+        x127.gf128_mul(&x).expose_secret(),
+        block("e1000000000000000000000000000000").expose_secret()
+    );
+}
+
+// ──────────────────────────────────────────────────────────────
+// clamp_x25519 — RFC 7748 scalar clamping
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn clamp_x25519_rfc7748_scalar() {
+    // RFC 7748 §5.2, first test vector's input scalar
+    let mut scalar = secure_gate::Fixed::<[u8; 32]>::from_hex(
+        "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+    )
+    .unwrap();
+    scalar.clamp_x25519();
+    assert_eq!(
+        scalar.expose_secret(),
+        secure_gate::Fixed::<[u8; 32]>::from_hex(
+            "a046e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449a44",
+        )
+        .unwrap()
+        .expose_secret()
+    );
+}
+
+#[test]
+fn clamp_x25519_touches_only_the_specified_bits() {
+    let mut ones = secure_gate::Fixed::new([0xFFu8; 32]);
+    ones.clamp_x25519();
+    let mut expected = [0xFFu8; 32];
+    expected[0] = 0xF8;
+    expected[31] = 0x7F;
+    assert_eq!(ones.expose_secret(), &expected);
+
+    let mut zeros = secure_gate::Fixed::new([0u8; 32]);
+    zeros.clamp_x25519();
+    let mut expected = [0u8; 32];
+    expected[31] = 0x40;
+    assert_eq!(zeros.expose_secret(), &expected);
+
+    // Idempotent
+    let before = *ones.expose_secret();
+    ones.clamp_x25519();
+    assert_eq!(ones.expose_secret(), &before);
+}
+
+// ──────────────────────────────────────────────────────────────
+// from_env_hex — keys from environment variables
+// ──────────────────────────────────────────────────────────────
+
+// Each test uses its own variable name: tests run in parallel and share the environment.
+
+#[cfg(feature = "std")]
+#[test]
+fn fixed_from_env_hex_valid() {
+    std::env::set_var("SECURE_GATE_TEST_ENV_VALID", "00112233445566778899aabbccddeeff");
+    let key = secure_gate::Fixed::<[u8; 16]>::from_env_hex("SECURE_GATE_TEST_ENV_VALID").unwrap();
+    assert_eq!(key.expose_secret()[0], 0x00);
+    assert_eq!(key.expose_secret()[15], 0xff);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fixed_from_env_hex_distinct_errors() {
+    type Key = secure_gate::Fixed<[u8; 4]>;
+
+    assert_eq!(
+        Key::from_env_hex("SECURE_GATE_TEST_ENV_MISSING").unwrap_err(),
+        "environment variable not set"
+    );
+
+    std::env::set_var("SECURE_GATE_TEST_ENV_SHORT", "deadbe");
+    assert_eq!(
+        Key::from_env_hex("SECURE_GATE_TEST_ENV_SHORT").unwrap_err(),
+        "hex string length mismatch"
+    );
+
+    std::env::set_var("SECURE_GATE_TEST_ENV_BADHEX", "deadbeeg");
+    assert_eq!(
+        Key::from_env_hex("SECURE_GATE_TEST_ENV_BADHEX").unwrap_err(),
+        "invalid hex string"
+    );
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn fixed_from_env_hex_not_unicode() {
+    use std::os::unix::ffi::OsStrExt;
+    std::env::set_var(
+        "SECURE_GATE_TEST_ENV_NOTUTF8",
+        std::ffi::OsStr::from_bytes(&[0xff, 0xfe]),
+    );
+    assert_eq!(
+        secure_gate::Fixed::<[u8; 1]>::from_env_hex("SECURE_GATE_TEST_ENV_NOTUTF8").unwrap_err(),
+        "environment variable is not valid unicode"
+    );
+}
+
+// ──────────────────────────────────────────────────────────────
+// subtle::ConstantTimeEq interop
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_subtle_trait_agrees_with_inherent_ct_eq() {
+    use subtle::ConstantTimeEq;
+
+    let a = Fixed::new([0x11u8; 32]);
+    let b = Fixed::new([0x11u8; 32]);
+    let mut bytes = [0x11u8; 32];
+    bytes[31] ^= 1;
+    let c = Fixed::new(bytes);
+
+    for (x, y) in [(&a, &b), (&a, &c), (&c, &c)] {
+        let via_trait: bool = ConstantTimeEq::ct_eq(x, y).into();
+        assert_eq!(via_trait, x.ct_eq(y));
+    }
+    assert!(bool::from(ConstantTimeEq::ct_ne(&a, &c)));
+}
+
+#[test]
+fn fixed_subtle_trait_in_generic_code() {
+    fn all_equal<T: subtle::ConstantTimeEq>(items: &[T]) -> bool {
+        items
+            .windows(2)
+            .fold(subtle::Choice::from(1), |acc, w| acc & w[0].ct_eq(&w[1]))
+            .into()
+    }
+
+    let keys = [Fixed::new([7u8; 16]), Fixed::new([7u8; 16]), Fixed::new([7u8; 16])];
+    assert!(all_equal(&keys));
+    assert!(!all_equal(&[Fixed::new([7u8; 16]), Fixed::new([8u8; 16])]));
+}
+
+// ──────────────────────────────────────────────────────────────
+// fingerprint — loggable truncated BLAKE3 (fingerprint feature)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn fixed_fingerprint_is_stable_and_short() {
+    let a = Fixed::new([0x11u8; 32]);
+    let b = Fixed::new([0x11u8; 32]);
+    let fp = a.fingerprint();
+    assert_eq!(fp, b.fingerprint());
+    assert_eq!(fp.len(), 8);
+    assert!(fp.bytes().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+}
+
+#[cfg(feature = "fingerprint")]
+#[test]
+fn fixed_fingerprint_distinguishes_secrets() {
+    use std::collections::HashSet;
+
+    let mut seen = HashSet::new();
+    for i in 0..256u16 {
+        let mut bytes = [0u8; 16];
+        bytes[..2].copy_from_slice(&i.to_le_bytes());
+        seen.insert(Fixed::new(bytes).fingerprint());
+    }
+    // 256 secrets in a 2^32 space: a collision here would be a ~1e-5 fluke
+    assert_eq!(seen.len(), 256);
+}
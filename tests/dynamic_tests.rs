@@ -3,6 +3,8 @@
 // ==========================================================================
 // Comprehensive edge case testing for Dynamic type
 
+#![cfg(feature = "alloc")]
+
 use secure_gate::Dynamic;
 
 // ──────────────────────────────────────────────────────────────
@@ -312,6 +314,30 @@ fn dynamic_clone_vec_isolation() {
     assert_eq!(data2.expose_secret(), &[1, 2, 3, 4]);
 }
 
+#[test]
+fn dynamic_clone_vec_drops_spare_capacity() {
+    // Grow, then truncate: the tail past `len` still holds the old bytes
+    let mut data = Dynamic::<Vec<u8>>::new(vec![0xAA; 4]);
+    data.expose_secret_mut().extend_from_slice(&[0xBB; 1024]);
+    data.expose_secret_mut().truncate(4);
+    assert!(data.expose_secret().capacity() >= 1028);
+
+    let copy = data.clone();
+    assert_eq!(copy.expose_secret(), &[0xAA; 4]);
+    assert_eq!(copy.expose_secret().capacity(), copy.len());
+}
+
+#[test]
+fn dynamic_clone_string_drops_spare_capacity() {
+    let mut pw = Dynamic::<String>::new(String::with_capacity(512));
+    pw.expose_secret_mut().push_str("a much longer previous password");
+    pw.expose_secret_mut().truncate(6);
+
+    let copy = pw.clone();
+    assert_eq!(copy.expose_secret(), "a much");
+    assert_eq!(copy.expose_secret().capacity(), copy.len());
+}
+
 #[test]
 fn dynamic_clone_empty() {
     let empty_str1 = Dynamic::<String>::new("".to_string());
@@ -391,6 +417,30 @@ fn dynamic_ct_eq_string_different() {
     assert!(!pw1.ct_eq(&pw2));
 }
 
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_ct_eq_ignore_ascii_case_mixed_case_equal() {
+    let token: Dynamic<String> = "Bearer-XyZ_09".into();
+    assert!(token.ct_eq_ignore_ascii_case("bearer-xyz_09"));
+    assert!(token.ct_eq_ignore_ascii_case("BEARER-XYZ_09"));
+    assert!(token.ct_eq_ignore_ascii_case("Bearer-XyZ_09"));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_ct_eq_ignore_ascii_case_different() {
+    let token: Dynamic<String> = "Bearer-XyZ_09".into();
+    assert!(!token.ct_eq_ignore_ascii_case("bearer-xyz_08"));
+    assert!(!token.ct_eq_ignore_ascii_case("bearer-xyz_0"));
+    // Only letters fold: '@' (0x40) and '`' (0x60) differ by 0x20 but are not a case pair.
+    let sym: Dynamic<String> = "@[".into();
+    assert!(!sym.ct_eq_ignore_ascii_case("`{"));
+    // Non-ASCII is compared exactly.
+    let uni: Dynamic<String> = "ÄB".into();
+    assert!(!uni.ct_eq_ignore_ascii_case("äb"));
+    assert!(uni.ct_eq_ignore_ascii_case("Äb"));
+}
+
 #[cfg(feature = "conversions")]
 #[test]
 fn dynamic_ct_eq_vec_same() {
@@ -891,3 +941,1154 @@ fn dynamic_multiple_types_together() {
     assert_eq!(ints.expose_secret(), &[10, 20, 30]);
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// core::fmt::Write for Dynamic<String>
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_string_fmt_write() {
+    use core::fmt::Write;
+
+    let (word, digit) = ("hunter", 2);
+    let mut secret = Dynamic::<String>::new(String::new());
+    write!(secret, "{word}{digit}").unwrap();
+    writeln!(secret, "!").unwrap();
+
+    assert_eq!(secret.expose_secret(), "hunter2!\n");
+    assert_eq!(secret.len(), 9);
+}
+
+#[test]
+fn dynamic_string_fmt_write_grows_past_capacity() {
+    use core::fmt::Write;
+
+    let mut secret = Dynamic::<String>::new(String::with_capacity(4));
+    for _ in 0..64 {
+        write!(secret, "ab").unwrap();
+    }
+
+    assert_eq!(secret.len(), 128);
+    assert!(secret.expose_secret().chars().all(|c| c == 'a' || c == 'b'));
+}
+
+// ──────────────────────────────────────────────────────────────
+// shrink_and_wipe
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_vec_shrink_and_wipe() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![7u8; 64]);
+    data.expose_secret_mut().truncate(4);
+    assert!(data.expose_secret().capacity() >= 64);
+
+    data.shrink_and_wipe();
+    assert_eq!(data.expose_secret(), &[7, 7, 7, 7]);
+    assert_eq!(data.expose_secret().capacity(), 4);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_string_shrink_and_wipe() {
+    let mut pw = Dynamic::<String>::new(String::with_capacity(32));
+    pw.expose_secret_mut().push_str("hunter2");
+
+    pw.shrink_and_wipe();
+    assert_eq!(pw.expose_secret(), "hunter2");
+    assert_eq!(pw.expose_secret().capacity(), 7);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_shrink_and_wipe_no_spare_capacity_is_noop() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    data.shrink_and_wipe();
+    assert_eq!(data.expose_secret(), &[1, 2, 3]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_eq_str
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_string_ct_eq_str() {
+    let stored: Dynamic<String> = "correct horse".into();
+
+    assert!(stored.ct_eq_str("correct horse"));
+    assert!(!stored.ct_eq_str("correct horsf"));
+    assert!(!stored.ct_eq_str("Correct horse"));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_string_ct_eq_str_length_mismatch() {
+    let stored: Dynamic<String> = "hunter2".into();
+
+    assert!(!stored.ct_eq_str("hunter"));
+    assert!(!stored.ct_eq_str("hunter22"));
+    assert!(!stored.ct_eq_str(""));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_string_ct_eq_str_empty() {
+    let stored: Dynamic<String> = "".into();
+    assert!(stored.ct_eq_str(""));
+}
+
+// ──────────────────────────────────────────────────────────────
+// map_bytes_in_place
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_map_bytes_in_place_complement_roundtrip() {
+    let original = vec![0x00u8, 0x0F, 0xA5, 0xFF];
+    let mut data = Dynamic::<Vec<u8>>::new(original.clone());
+    let ptr = data.expose_secret().as_ptr();
+
+    data.map_bytes_in_place(|b| !b);
+    assert_eq!(data.expose_secret(), &[0xFF, 0xF0, 0x5A, 0x00]);
+
+    data.map_bytes_in_place(|b| !b);
+    assert_eq!(data.expose_secret(), &original);
+    assert_eq!(data.expose_secret().as_ptr(), ptr); // same allocation
+}
+
+#[test]
+fn dynamic_map_bytes_in_place_empty() {
+    let mut data = Dynamic::<Vec<u8>>::new(Vec::new());
+    data.map_bytes_in_place(|_| unreachable!());
+    assert!(data.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// IntoIterator for Dynamic<Vec<u8>>
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_vec_into_iter_yields_all_bytes() {
+    let data = Dynamic::<Vec<u8>>::new(vec![1, 2, 3, 4]);
+    let mut collected = Vec::new();
+    for b in data {
+        collected.push(b);
+    }
+    assert_eq!(collected, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn dynamic_vec_into_iter_exact_size_and_partial() {
+    let data = Dynamic::<Vec<u8>>::new(vec![9u8; 10]);
+    let mut iter = data.into_iter();
+    assert_eq!(iter.len(), 10);
+
+    assert_eq!(iter.next(), Some(9));
+    assert_eq!(iter.next(), Some(9));
+    assert_eq!(iter.len(), 8);
+    assert_eq!(format!("{iter:?}"), "[REDACTED]");
+    drop(iter); // abandoned early — remaining bytes wiped under zeroize
+}
+
+#[test]
+fn dynamic_vec_into_iter_empty() {
+    let data = Dynamic::<Vec<u8>>::new(Vec::new());
+    let mut iter = data.into_iter();
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+// ──────────────────────────────────────────────────────────────
+// take
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_vec_take_leaves_empty_zero_capacity() {
+    let mut slot = Dynamic::<Vec<u8>>::new(vec![0xAAu8; 32]);
+    let ptr = slot.expose_secret().as_ptr();
+
+    let taken = slot.take();
+    assert_eq!(taken.expose_secret(), &[0xAAu8; 32]);
+    assert_eq!(taken.expose_secret().as_ptr(), ptr); // moved, not copied
+    assert!(slot.is_empty());
+    assert_eq!(slot.expose_secret().capacity(), 0);
+}
+
+#[test]
+fn dynamic_string_take_leaves_empty_zero_capacity() {
+    let mut slot: Dynamic<String> = "hunter2".into();
+
+    let taken = slot.take();
+    assert_eq!(taken.expose_secret(), "hunter2");
+    assert!(slot.is_empty());
+    assert_eq!(slot.expose_secret().capacity(), 0);
+
+    // Taking again yields an empty secret
+    assert!(slot.take().is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// clone_from_secure — reuse destination allocation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_clone_from_secure_shrink_reuses_allocation() {
+    let mut dest = Dynamic::<Vec<u8>>::new(vec![0xAA; 32]);
+    let src = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    let ptr = dest.expose_secret().as_ptr();
+    let cap = dest.expose_secret().capacity();
+
+    dest.clone_from_secure(&src);
+
+    assert_eq!(dest.expose_secret(), &[1, 2, 3]);
+    assert_eq!(dest.expose_secret().as_ptr(), ptr);
+    assert_eq!(dest.expose_secret().capacity(), cap);
+    assert_eq!(src.expose_secret(), &[1, 2, 3]);
+}
+
+#[test]
+fn dynamic_clone_from_secure_grow_within_capacity() {
+    let mut buf = Vec::with_capacity(16);
+    buf.extend_from_slice(b"ab");
+    let mut dest = Dynamic::<Vec<u8>>::new(buf);
+    let ptr = dest.expose_secret().as_ptr();
+
+    dest.clone_from_secure(&Dynamic::new(b"0123456789".to_vec()));
+
+    assert_eq!(dest.expose_secret(), b"0123456789");
+    assert_eq!(dest.expose_secret().as_ptr(), ptr);
+}
+
+#[test]
+fn dynamic_clone_from_secure_grow_beyond_capacity() {
+    let mut dest = Dynamic::<Vec<u8>>::new(Vec::new());
+    let src = Dynamic::<Vec<u8>>::new(vec![7u8; 100]);
+
+    dest.clone_from_secure(&src);
+
+    assert_eq!(dest.expose_secret(), src.expose_secret());
+}
+
+#[test]
+fn dynamic_clone_from_secure_empty_source() {
+    let mut dest = Dynamic::<Vec<u8>>::new(b"secret".to_vec());
+    dest.clone_from_secure(&Dynamic::new(Vec::new()));
+    assert!(dest.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// guard / guard_with — scoped exposure with cleanup
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_guard_derefs_and_writes_through() {
+    let mut secret = Dynamic::<Vec<u8>>::new(b"abc".to_vec());
+    {
+        let mut g = secret.guard();
+        assert_eq!(&*g, b"abc");
+        g.push(b'd');
+        assert_eq!(format!("{g:?}"), "[REDACTED]");
+    }
+    assert_eq!(secret.expose_secret(), b"abcd");
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_guard_shrinks_spare_capacity_on_drop() {
+    let mut secret = Dynamic::<Vec<u8>>::new(vec![0xAA; 64]);
+    {
+        let mut g = secret.guard();
+        g.truncate(8);
+    }
+    assert_eq!(secret.expose_secret(), &[0xAA; 8]);
+    assert_eq!(secret.expose_secret().capacity(), 8);
+
+    let mut pw: Dynamic<String> = "correct horse battery staple".into();
+    pw.guard().truncate(7);
+    assert_eq!(pw.expose_secret(), "correct");
+    assert_eq!(pw.expose_secret().capacity(), 7);
+}
+
+#[test]
+fn dynamic_guard_with_runs_hook_once_on_drop() {
+    let mut secret = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    {
+        let mut g = secret.guard_with(|v| v.push(0xFF));
+        g[0] = 9;
+        assert_eq!(&*g, &[9, 2, 3]); // hook has not run yet
+    }
+    assert_eq!(secret.expose_secret(), &[9, 2, 3, 0xFF]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// ptr_eq — aliasing check
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_ptr_eq_self_and_clone() {
+    let x = Dynamic::<Vec<u8>>::new(vec![0xAB; 16]);
+    assert!(x.ptr_eq(&x));
+    assert!(!x.ptr_eq(&x.clone()));
+
+    let s: Dynamic<String> = "secret".into();
+    let t: Dynamic<String> = "secret".into();
+    assert!(!s.ptr_eq(&t)); // equal contents, distinct allocations
+}
+
+#[test]
+fn dynamic_ptr_eq_unsized() {
+    let x: Dynamic<str> = Dynamic::new_boxed("abc".into());
+    assert!(x.ptr_eq(&x));
+}
+
+// ──────────────────────────────────────────────────────────────
+// into_pinned / from_pinned
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_pinned_roundtrip_reuses_allocation() {
+    let secret = Dynamic::<Vec<u8>>::new(vec![0xAB; 16]);
+    let ptr = secret.expose_secret().as_ptr();
+
+    let pinned = secret.into_pinned();
+    assert_eq!(pinned.as_ptr(), ptr);
+
+    let back = Dynamic::from_pinned(pinned);
+    assert_eq!(back.expose_secret(), &[0xAB; 16]);
+    assert_eq!(back.expose_secret().as_ptr(), ptr);
+}
+
+#[test]
+fn dynamic_into_pinned_not_unpin() {
+    use core::marker::PhantomPinned;
+
+    struct Anchored {
+        key: [u8; 4],
+        _pin: PhantomPinned,
+    }
+
+    let secret = Dynamic::new(Anchored {
+        key: [7; 4],
+        _pin: PhantomPinned,
+    });
+    let pinned = secret.into_pinned();
+    assert_eq!(pinned.as_ref().get_ref().key, [7; 4]);
+}
+
+#[test]
+fn dynamic_from_pinned_unsized_str() {
+    let pinned: core::pin::Pin<Box<str>> = Box::into_pin("abc".into());
+    let secret = Dynamic::<str>::from_pinned(pinned);
+    assert_eq!(secret.expose_secret(), "abc");
+}
+
+// ──────────────────────────────────────────────────────────────
+// reserve_exact_secure — one-shot secure preallocation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_reserve_exact_secure_then_push_never_reallocates() {
+    let mut secret = Dynamic::<Vec<u8>>::new(b"seed".to_vec());
+    secret.reserve_exact_secure(60);
+    assert_eq!(secret.expose_secret(), b"seed");
+
+    let cap = secret.expose_secret().capacity();
+    assert!(cap >= 64);
+    let ptr = secret.expose_secret().as_ptr();
+
+    while secret.len() < cap {
+        secret.expose_secret_mut().push(0xAA);
+        assert_eq!(secret.expose_secret().as_ptr(), ptr);
+    }
+    assert_eq!(secret.expose_secret().capacity(), cap);
+}
+
+#[test]
+fn dynamic_reserve_exact_secure_noop_when_capacity_suffices() {
+    let mut secret = Dynamic::<Vec<u8>>::new(Vec::with_capacity(32));
+    secret.expose_secret_mut().extend_from_slice(b"abc");
+    let ptr = secret.expose_secret().as_ptr();
+
+    secret.reserve_exact_secure(16);
+    assert_eq!(secret.expose_secret().as_ptr(), ptr);
+    assert_eq!(secret.expose_secret().capacity(), 32);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn dynamic_reserve_exact_secure_overflow_panics() {
+    let mut secret = Dynamic::<Vec<u8>>::new(vec![1u8]);
+    secret.reserve_exact_secure(usize::MAX);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Dynamic<String> <-> Dynamic<Vec<u8>> conversions
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_string_into_bytes_moves_buffer() {
+    let pw: Dynamic<String> = "correct horse".into();
+    let ptr = pw.expose_secret().as_ptr();
+
+    let bytes: Dynamic<Vec<u8>> = pw.into();
+    assert_eq!(bytes.expose_secret(), b"correct horse");
+    assert_eq!(bytes.expose_secret().as_ptr(), ptr);
+}
+
+#[test]
+fn dynamic_bytes_try_into_string_moves_buffer() {
+    let bytes = Dynamic::<Vec<u8>>::new("pässwörd".as_bytes().to_vec());
+    let ptr = bytes.expose_secret().as_ptr();
+
+    let pw: Dynamic<String> = bytes.try_into().unwrap();
+    assert_eq!(pw.expose_secret(), "pässwörd");
+    assert_eq!(pw.expose_secret().as_ptr(), ptr);
+}
+
+#[test]
+fn dynamic_bytes_try_into_string_invalid_returns_original() {
+    let bytes = Dynamic::<Vec<u8>>::new(vec![b'o', b'k', 0xC3]); // truncated UTF-8
+    let ptr = bytes.expose_secret().as_ptr();
+
+    let err = Dynamic::<String>::try_from(bytes).unwrap_err();
+    assert_eq!(err.expose_secret(), &[b'o', b'k', 0xC3]);
+    assert_eq!(err.expose_secret().as_ptr(), ptr);
+    assert_eq!(format!("{err:?}"), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// disarm — ownership transfer without wiping
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_disarm_returns_box_untouched() {
+    let secret = Dynamic::<Vec<u8>>::new(vec![0xAB; 32]);
+    let ptr = secret.expose_secret().as_ptr();
+
+    let raw = secret.disarm();
+    assert_eq!(raw.as_ptr(), ptr); // same allocation, no copy
+    assert_eq!(*raw, vec![0xAB; 32]); // not wiped
+
+    let s: Dynamic<str> = Dynamic::new_boxed("ffi".into());
+    assert_eq!(&*s.disarm(), "ffi");
+}
+
+// ──────────────────────────────────────────────────────────────
+// clone_no_clone — independent non-cloneable copy
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_clone_no_clone_is_independent() {
+    let source: Dynamic<String> = "hunter2".into();
+    let mut copy = source.clone_no_clone();
+    assert_eq!(copy.expose_secret(), "hunter2");
+
+    copy.expose_secret_mut().push('!');
+    assert_eq!(copy.expose_secret(), "hunter2!");
+    assert_eq!(source.expose_secret(), "hunter2");
+    assert_ne!(copy.expose_secret().as_ptr(), source.expose_secret().as_ptr());
+
+    // The source is still cloneable; the copy is not
+    let _source_clone = source.clone();
+    // copy.clone(); // compile error — correct (also checked by a compile_fail doctest)
+    assert_eq!(format!("{copy:?}"), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// fill — overwrite with a constant
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_fill_sets_every_byte_keeps_len() {
+    let mut secret = Dynamic::<Vec<u8>>::new((0u8..100).collect::<Vec<_>>());
+    secret.fill(0xA5);
+    assert_eq!(secret.len(), 100);
+    assert!(secret.expose_secret().iter().all(|&b| b == 0xA5));
+
+    let mut empty = Dynamic::<Vec<u8>>::new(Vec::new());
+    empty.fill(0xFF);
+    assert!(empty.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_eq_padded — length-hiding comparison
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_ct_eq_padded_equal_and_unequal() {
+    let stored = Dynamic::<Vec<u8>>::new(vec![9, 8, 7, 6]);
+    assert!(stored.ct_eq_padded(&Dynamic::new(vec![9, 8, 7, 6]), 32));
+    assert!(!stored.ct_eq_padded(&Dynamic::new(vec![9, 8, 7, 5]), 32));
+    assert!(!stored.ct_eq_padded(&Dynamic::new(vec![0, 8, 7, 6]), 32));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_ct_eq_padded_zero_padding_is_not_equality() {
+    let short = Dynamic::<Vec<u8>>::new(vec![1, 2]);
+    let padded = Dynamic::<Vec<u8>>::new(vec![1, 2, 0, 0]);
+    assert!(!short.ct_eq_padded(&padded, 8));
+    assert!(!padded.ct_eq_padded(&short, 8));
+    assert!(!Dynamic::<Vec<u8>>::new(Vec::new()).ct_eq_padded(&Dynamic::new(vec![0]), 8));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_ct_eq_padded_exact_bound_and_overflow() {
+    let a = Dynamic::<Vec<u8>>::new(vec![5; 16]);
+    assert!(a.ct_eq_padded(&Dynamic::new(vec![5; 16]), 16));
+    assert!(!a.ct_eq_padded(&Dynamic::new(vec![5; 16]), 15));
+
+    let empty = Dynamic::<Vec<u8>>::new(Vec::new());
+    assert!(empty.ct_eq_padded(&Dynamic::new(Vec::new()), 0));
+    assert!(empty.ct_eq_padded(&Dynamic::new(Vec::new()), 4));
+}
+
+// ──────────────────────────────────────────────────────────────
+// FromIterator<u8>
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_collect_from_exact_size_iterator() {
+    let source = vec![0xAAu8, 0xBB, 0xCC];
+    let secret: Dynamic<Vec<u8>> = source.iter().copied().collect();
+    assert_eq!(secret.expose_secret(), &source);
+}
+
+#[test]
+fn dynamic_collect_grows_past_size_hint() {
+    // `filter` reports a lower bound of 0, so every byte goes through secure growth
+    let secret: Dynamic<Vec<u8>> = (0u8..=255).filter(|b| b % 3 == 0).collect();
+    let expected: Vec<u8> = (0u8..=255).filter(|b| b % 3 == 0).collect();
+    assert_eq!(secret.expose_secret(), &expected);
+}
+
+#[test]
+fn dynamic_collect_empty() {
+    let secret: Dynamic<Vec<u8>> = core::iter::empty().collect();
+    assert!(secret.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// retain_secure — wipes the vacated tail
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_retain_secure_keeps_order() {
+    let mut secret = Dynamic::<Vec<u8>>::new(vec![5, 1, 5, 2, 5, 3]);
+    secret.retain_secure(|b| b != 5);
+    assert_eq!(secret.expose_secret(), &[1, 2, 3]);
+
+    secret.retain_secure(|_| false);
+    assert!(secret.is_empty());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_retain_secure_wipes_tail() {
+    let mut secret = Dynamic::<Vec<u8>>::new(vec![0xAA, 0x11, 0xBB, 0x22, 0xCC, 0x33]);
+    secret.retain_secure(|b| b >= 0xA0);
+    assert_eq!(secret.expose_secret(), &[0xAA, 0xBB, 0xCC]);
+
+    // The three vacated slots are still in the allocation — they must be zero.
+    let tail = secret.expose_secret_mut().spare_capacity_mut();
+    assert!(tail.len() >= 3);
+    for slot in &tail[..3] {
+        // SAFETY: these bytes were initialized before truncation and the allocation
+        // has not been touched since.
+        assert_eq!(unsafe { slot.assume_init() }, 0);
+    }
+}
+
+// ──────────────────────────────────────────────────────────────
+// as_box / as_box_mut
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+#[allow(clippy::borrowed_box)]
+fn dynamic_as_box_is_the_inner_allocation() {
+    fn takes_box(b: &Box<Vec<u8>>) -> *const u8 {
+        b.as_ptr()
+    }
+
+    let mut secret = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    assert_eq!(takes_box(secret.as_box()), secret.expose_secret().as_ptr());
+
+    secret.as_box_mut().push(4);
+    assert_eq!(secret.expose_secret(), &[1, 2, 3, 4]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Length-prefixed framing
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_length_prefixed_roundtrip() {
+    let secret = Dynamic::<Vec<u8>>::new((0u8..=255).collect::<Vec<u8>>());
+    let framed = secret.to_length_prefixed();
+    assert_eq!(&framed[..4], &[0, 0, 1, 0]);
+
+    let (back, used) = Dynamic::<Vec<u8>>::from_length_prefixed(&framed).unwrap();
+    assert_eq!(used, framed.len());
+    assert_eq!(back.expose_secret(), secret.expose_secret());
+}
+
+#[test]
+fn dynamic_length_prefixed_truncated_input() {
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_length_prefixed(&[0, 0, 0]).unwrap_err(),
+        "length prefix truncated"
+    );
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_length_prefixed(&[0, 0, 0, 3, 1, 2]).unwrap_err(),
+        "frame truncated"
+    );
+}
+
+#[test]
+fn dynamic_length_prefixed_oversized_declared_length() {
+    // A hostile prefix claiming ~4 GiB must fail without allocating it
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_length_prefixed(&[0xFF, 0xFF, 0xFF, 0xFF, 1]).unwrap_err(),
+        "frame truncated"
+    );
+
+    let framed = Dynamic::<Vec<u8>>::new(vec![7u8; 64]).to_length_prefixed();
+    assert_eq!(
+        Dynamic::<Vec<u8>>::from_length_prefixed_max(&framed, 32).unwrap_err(),
+        "frame exceeds length limit"
+    );
+    assert!(Dynamic::<Vec<u8>>::from_length_prefixed_max(&framed, 64).is_ok());
+}
+
+// ──────────────────────────────────────────────────────────────
+// PKCS#7 padding
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_pkcs7_pad_unpad_roundtrip() {
+    for len in 0..40 {
+        let original: Vec<u8> = (0..len as u8).collect();
+        let mut data = Dynamic::<Vec<u8>>::new(original.clone());
+        data.pad_pkcs7(16);
+        assert_eq!(data.len() % 16, 0);
+        assert!(data.len() > original.len());
+
+        data.unpad_pkcs7().unwrap();
+        assert_eq!(data.expose_secret(), &original);
+    }
+}
+
+#[test]
+fn dynamic_pkcs7_block_aligned_gets_full_block() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![0xAB; 16]);
+    data.pad_pkcs7(16);
+    assert_eq!(data.len(), 32);
+    assert_eq!(&data.expose_secret()[16..], &[16u8; 16]);
+}
+
+#[test]
+fn dynamic_pkcs7_invalid_padding_rejected_unchanged() {
+    let cases: [&[u8]; 5] = [
+        &[],                 // empty
+        &[1, 2, 3, 0],       // zero pad byte
+        &[1, 2, 3, 4, 5],    // pad longer than data
+        &[1, 2, 3, 4, 3, 3], // one pad byte wrong
+        &[9, 9, 2, 3],       // last byte disagrees
+    ];
+    for case in cases {
+        let mut data = Dynamic::<Vec<u8>>::new(case.to_vec());
+        assert_eq!(data.unpad_pkcs7(), Err("invalid PKCS#7 padding"), "{case:?}");
+        assert_eq!(data.expose_secret().as_slice(), case);
+    }
+}
+
+#[test]
+fn dynamic_pkcs7_max_block_size() {
+    let mut data = Dynamic::<Vec<u8>>::new(Vec::new());
+    data.pad_pkcs7(255);
+    assert_eq!(data.expose_secret(), &vec![255u8; 255]);
+    data.unpad_pkcs7().unwrap();
+    assert!(data.is_empty());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_pkcs7_unpad_wipes_removed_bytes() {
+    let mut data = Dynamic::<Vec<u8>>::new(vec![7, 7, 3, 3, 3]);
+    data.unpad_pkcs7().unwrap();
+    assert_eq!(data.expose_secret(), &[7, 7]);
+
+    let tail = data.expose_secret_mut().spare_capacity_mut();
+    for slot in &tail[..3] {
+        // SAFETY: these bytes were initialized before truncation
+        assert_eq!(unsafe { slot.assume_init() }, 0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "block size")]
+fn dynamic_pkcs7_zero_block_size_panics() {
+    Dynamic::<Vec<u8>>::new(vec![1]).pad_pkcs7(0);
+}
+
+// ──────────────────────────────────────────────────────────────
+// #[derive(Zeroize)] on structs containing Dynamic (feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[derive(zeroize::Zeroize)]
+struct Session {
+    token: Dynamic<String>,
+    key: Dynamic<Vec<u8>>,
+    counter: u64,
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_fields_zeroize_through_derive() {
+    use zeroize::Zeroize;
+
+    let mut session = Session {
+        token: "bearer abc".into(),
+        key: vec![0xAAu8; 32].into(),
+        counter: 9,
+    };
+    session.zeroize();
+
+    assert!(session.token.is_empty());
+    assert!(session.key.is_empty());
+    assert_eq!(session.counter, 0);
+}
+
+#[cfg(feature = "zeroize")]
+#[derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop)]
+struct WipedOnDrop {
+    secret: Dynamic<Vec<u8>>,
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_fields_support_derive_zeroize_on_drop() {
+    fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>(_: &T) {}
+
+    let wrapper = WipedOnDrop {
+        secret: vec![1u8, 2, 3].into(),
+    };
+    assert_zeroize_on_drop(&wrapper);
+    assert_eq!(wrapper.secret.expose_secret(), &[1, 2, 3]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// zeroize::Zeroizing <-> Dynamic (feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_from_zeroizing_moves_buffer() {
+    let legacy = zeroize::Zeroizing::new(vec![9u8; 48]);
+    let ptr = legacy.as_ptr();
+
+    let secret: Dynamic<Vec<u8>> = legacy.into();
+    assert_eq!(secret.expose_secret(), &vec![9u8; 48]);
+    assert_eq!(secret.expose_secret().as_ptr(), ptr, "heap buffer should move, not copy");
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_into_zeroizing_moves_buffer() {
+    let secret: Dynamic<String> = "migrate me".into();
+    let ptr = secret.expose_secret().as_ptr();
+
+    let legacy: zeroize::Zeroizing<String> = secret.into();
+    assert_eq!(legacy.as_str(), "migrate me");
+    assert_eq!(legacy.as_ptr(), ptr);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_zeroizing_roundtrip() {
+    let start = zeroize::Zeroizing::new(vec![1u8, 2, 3]);
+    let secret: Dynamic<Vec<u8>> = start.into();
+    let back: zeroize::Zeroizing<Vec<u8>> = secret.into();
+    assert_eq!(*back, [1, 2, 3]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_into_inner_zeroizing_moves_buffer() {
+    let secret: Dynamic<Vec<u8>> = vec![7u8; 24].into();
+    let ptr = secret.expose_secret().as_ptr();
+
+    let inner = secret.into_inner_zeroizing();
+    assert_eq!(*inner, vec![7u8; 24]);
+    assert_eq!(inner.as_ptr(), ptr);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_into_inner_zeroizing_wipes_on_drop() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct Probe(Rc<Cell<bool>>);
+    impl zeroize::Zeroize for Probe {
+        fn zeroize(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let wiped = Rc::new(Cell::new(false));
+    let inner = Dynamic::new(Probe(wiped.clone())).into_inner_zeroizing();
+    assert!(!wiped.get());
+    drop(inner);
+    assert!(wiped.get());
+}
+
+// ──────────────────────────────────────────────────────────────
+// leak_static — process-lifetime secrets
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_leak_static_outlives_wrapper() {
+    fn load() -> &'static Vec<u8> {
+        Dynamic::<Vec<u8>>::new(vec![0xC0, 0xFF, 0xEE]).leak_static()
+    }
+
+    let key = load();
+    assert_eq!(key, &[0xC0, 0xFF, 0xEE]);
+
+    let handle = std::thread::spawn(move || key.len());
+    assert_eq!(handle.join().unwrap(), 3);
+}
+
+#[test]
+fn dynamic_leak_static_unsized() {
+    let boxed: Box<str> = "config-token".into();
+    let token: &'static str = Dynamic::<str>::new_boxed(boxed).leak_static();
+    assert_eq!(token, "config-token");
+}
+
+// ──────────────────────────────────────────────────────────────
+// content_fingerprint — change detection
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_content_fingerprint_stable_for_equal_content() {
+    let a = Dynamic::<Vec<u8>>::new(b"rotating-config".to_vec());
+    let mut b = Dynamic::<Vec<u8>>::new(Vec::with_capacity(64));
+    b.expose_secret_mut().extend_from_slice(b"rotating-config");
+    assert_eq!(a.content_fingerprint(), b.content_fingerprint());
+    assert_eq!(a.content_fingerprint(), a.clone().content_fingerprint());
+}
+
+#[test]
+fn dynamic_content_fingerprint_detects_changes() {
+    let mut secret = Dynamic::<Vec<u8>>::new(vec![0u8; 32]);
+    let before = secret.content_fingerprint();
+    secret.expose_secret_mut()[31] = 1;
+    assert_ne!(secret.content_fingerprint(), before);
+
+    // Length is part of the content
+    let empty = Dynamic::<Vec<u8>>::new(Vec::new());
+    let zero = Dynamic::<Vec<u8>>::new(vec![0]);
+    assert_ne!(empty.content_fingerprint(), zero.content_fingerprint());
+}
+
+// ──────────────────────────────────────────────────────────────
+// AuditedDynamicVec — growth accounting (audit feature)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "audit")]
+#[test]
+fn audited_vec_stress_growth_counts_every_retired_allocation() {
+    use secure_gate::AuditedDynamicVec;
+
+    let mut buf = AuditedDynamicVec::new();
+    let mut capacity_changes = 0;
+    let mut last_cap = buf.capacity();
+    for i in 0..20_000u32 {
+        if i % 3 == 0 {
+            buf.extend_from_slice(&i.to_le_bytes());
+        } else {
+            buf.push(i as u8);
+        }
+        if buf.capacity() != last_cap {
+            if last_cap != 0 {
+                capacity_changes += 1;
+            }
+            last_cap = buf.capacity();
+        }
+    }
+
+    assert!(buf.retired_allocations() > 5);
+    assert_eq!(buf.retired_allocations(), capacity_changes);
+
+    let mut expected = Vec::new();
+    for i in 0..20_000u32 {
+        if i % 3 == 0 {
+            expected.extend_from_slice(&i.to_le_bytes());
+        } else {
+            expected.push(i as u8);
+        }
+    }
+    assert_eq!(buf.len(), expected.len());
+    assert_eq!(buf.expose_secret(), expected.as_slice());
+}
+
+#[cfg(feature = "audit")]
+#[test]
+fn audited_vec_preallocated_never_retires() {
+    use secure_gate::AuditedDynamicVec;
+
+    let mut buf = AuditedDynamicVec::with_capacity(64);
+    for b in 0..64u8 {
+        buf.push(b);
+    }
+    assert_eq!(buf.retired_allocations(), 0);
+    assert_eq!(format!("{buf:?}"), "[REDACTED]");
+
+    let ptr = buf.expose_secret().as_ptr();
+    let secret = buf.into_dynamic();
+    assert_eq!(secret.expose_secret().as_ptr(), ptr);
+    assert_eq!(secret.len(), 64);
+}
+
+// ──────────────────────────────────────────────────────────────
+// from_cow — conditionally owned input
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_string_from_cow_borrowed_and_owned() {
+    use std::borrow::Cow;
+
+    let source = String::from("borrowed secret");
+    let borrowed = Dynamic::<String>::from_cow(Cow::Borrowed(source.as_str()));
+    assert_eq!(borrowed.expose_secret(), "borrowed secret");
+    assert_ne!(borrowed.expose_secret().as_ptr(), source.as_ptr()); // copied
+
+    let owned_value = String::from("owned secret");
+    let ptr = owned_value.as_ptr();
+    let owned = Dynamic::<String>::from_cow(Cow::Owned(owned_value));
+    assert_eq!(owned.expose_secret(), "owned secret");
+    assert_eq!(owned.expose_secret().as_ptr(), ptr); // moved
+}
+
+#[test]
+fn dynamic_vec_from_cow_borrowed_and_owned() {
+    use std::borrow::Cow;
+
+    let source = [0xDEu8, 0xAD, 0xBE, 0xEF];
+    let borrowed = Dynamic::<Vec<u8>>::from_cow(Cow::Borrowed(&source[..]));
+    assert_eq!(borrowed.expose_secret(), &source);
+
+    let owned_value = vec![9u8; 16];
+    let ptr = owned_value.as_ptr();
+    let owned = Dynamic::<Vec<u8>>::from_cow(Cow::Owned(owned_value));
+    assert_eq!(owned.expose_secret().as_ptr(), ptr);
+    assert_eq!(owned.len(), 16);
+}
+
+// ──────────────────────────────────────────────────────────────
+// len / is_empty are inherent (no Deref)
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_len_and_is_empty_are_inherent() {
+    // Path-qualified calls resolve only to inherent methods, never through `Deref`
+    let pw: Dynamic<String> = "hunter2".into();
+    assert_eq!(Dynamic::<String>::len(&pw), 7);
+    assert!(!Dynamic::<String>::is_empty(&pw));
+
+    let key = Dynamic::<Vec<u8>>::new(Vec::new());
+    assert_eq!(Dynamic::<Vec<u8>>::len(&key), 0);
+    assert!(Dynamic::<Vec<u8>>::is_empty(&key));
+
+    // Usable in const contexts, which `Deref`-based calls are not
+    const fn byte_len(secret: &Dynamic<String>) -> usize {
+        secret.len()
+    }
+    assert_eq!(byte_len(&pw), 7);
+}
+
+// ──────────────────────────────────────────────────────────────
+// expose_secret_guarded — re-entrancy detection (audit feature)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "audit")]
+#[test]
+fn guarded_exposure_allows_sequential_and_distinct_secrets() {
+    let a: Dynamic<String> = "alpha".into();
+    let b: Dynamic<String> = "bravo".into();
+    {
+        let ga = a.expose_secret_guarded();
+        let gb = b.expose_secret_guarded(); // different secret: fine
+        assert_eq!(&*ga, "alpha");
+        assert_eq!(gb.len(), 5);
+        assert_eq!(b.len(), 5); // metadata is not an exposure
+        assert_eq!(format!("{ga:?}"), "[REDACTED]");
+    }
+    let _again = a.expose_secret_guarded(); // previous guard released
+    assert_eq!(b.expose_secret(), "bravo");
+
+    // Zero-sized secrets share an address and are never tracked
+    let unit_a = Dynamic::new(());
+    let unit_b = Dynamic::new(());
+    let _ga = unit_a.expose_secret_guarded();
+    let _gb = unit_b.expose_secret_guarded();
+}
+
+#[cfg(all(feature = "audit", debug_assertions))]
+#[test]
+#[should_panic(expected = "nested exposure")]
+fn guarded_exposure_nested_guard_panics_in_debug() {
+    let key = Dynamic::<Vec<u8>>::new(vec![0xAA; 32]);
+    let _outer = key.expose_secret_guarded();
+    let _inner = key.expose_secret_guarded();
+}
+
+#[cfg(all(feature = "audit", debug_assertions))]
+#[test]
+#[should_panic(expected = "nested exposure")]
+fn guarded_exposure_then_plain_exposure_panics_in_debug() {
+    let key = Dynamic::<Vec<u8>>::new(vec![0xAA; 32]);
+    let _guard = key.expose_secret_guarded();
+    let _ = key.expose_secret();
+}
+
+// ──────────────────────────────────────────────────────────────
+// Fallible secure growth — try_reserve_exact_secure / try_extend_secure
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_try_growth_succeeds_like_infallible_versions() {
+    let mut secret = Dynamic::<Vec<u8>>::try_with_capacity(4).unwrap();
+    assert!(secret.expose_secret().capacity() >= 4);
+
+    for chunk in [&b"ab"[..], b"cdef", b"ghijklmnop"] {
+        secret.try_extend_secure(chunk).unwrap();
+    }
+    assert_eq!(secret.expose_secret(), b"abcdefghijklmnop");
+
+    secret.try_reserve_exact_secure(100).unwrap();
+    assert!(secret.expose_secret().capacity() >= 116);
+    assert_eq!(secret.expose_secret(), b"abcdefghijklmnop");
+}
+
+#[test]
+fn dynamic_try_growth_reports_failure_and_leaves_secret_intact() {
+    // Requests beyond `isize::MAX` always fail: a portable stand-in for OOM
+    let mut secret = Dynamic::<Vec<u8>>::new(b"intact".to_vec());
+    let ptr = secret.expose_secret().as_ptr();
+    let cap = secret.expose_secret().capacity();
+
+    assert!(secret.try_reserve_exact_secure(usize::MAX).is_err());
+    assert!(secret.try_reserve_exact_secure(isize::MAX as usize).is_err());
+    assert_eq!(secret.expose_secret(), b"intact");
+    assert_eq!(secret.expose_secret().as_ptr(), ptr);
+    assert_eq!(secret.expose_secret().capacity(), cap);
+
+    assert!(Dynamic::<Vec<u8>>::try_with_capacity(usize::MAX).is_err());
+}
+
+#[test]
+fn dynamic_try_extend_within_capacity_does_not_move() {
+    let mut secret = Dynamic::<Vec<u8>>::try_with_capacity(32).unwrap();
+    let ptr = secret.expose_secret().as_ptr();
+    secret.try_extend_secure(&[7u8; 32]).unwrap();
+    assert_eq!(secret.expose_secret().as_ptr(), ptr);
+    assert_eq!(secret.len(), 32);
+}
+
+// ──────────────────────────────────────────────────────────────
+// with_secret_mut — wipe on panic
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_with_secret_mut_returns_result_and_keeps_changes() {
+    let mut secret: Dynamic<String> = "pass".into();
+    let len = secret.with_secret_mut(|s| {
+        s.push_str("word");
+        s.len()
+    });
+    assert_eq!(len, 8);
+    assert_eq!(secret.expose_secret(), "password");
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_with_secret_mut_wipes_on_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut secret = Dynamic::<Vec<u8>>::new(vec![0xAA; 16]);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        secret.with_secret_mut(|v| {
+            v[..8].fill(0xBB); // half-modified...
+            panic!("update failed");
+        })
+    }));
+    assert!(result.is_err());
+    // `Vec::zeroize` wipes the contents and clears the length
+    assert!(secret.expose_secret().is_empty());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_with_secret_mut_fixed_size_wiped_in_place_on_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut secret = Dynamic::<[u8; 32]>::new([0x11u8; 32]);
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        secret.with_secret_mut(|k| {
+            k[0] = 0x22;
+            panic!("boom");
+        })
+    }));
+    assert_eq!(secret.expose_secret(), &[0u8; 32]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// downcast — type-erased secret stores
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_downcast_heterogeneous_store() {
+    use std::any::{Any, TypeId};
+    use std::collections::HashMap;
+
+    let mut store: HashMap<TypeId, Dynamic<dyn Any>> = HashMap::new();
+    store.insert(
+        TypeId::of::<String>(),
+        Dynamic::new_boxed(Box::new(String::from("db-password"))),
+    );
+    store.insert(
+        TypeId::of::<[u8; 32]>(),
+        Dynamic::new_boxed(Box::new([0x5Au8; 32])),
+    );
+
+    let password = store.remove(&TypeId::of::<String>()).unwrap();
+    assert!(password.is::<String>());
+    assert_eq!(format!("{password:?}"), "[REDACTED]");
+    let password: Dynamic<String> = password.downcast().unwrap();
+    assert_eq!(password.expose_secret(), "db-password");
+
+    let key: Dynamic<[u8; 32]> = store
+        .remove(&TypeId::of::<[u8; 32]>())
+        .unwrap()
+        .downcast()
+        .unwrap();
+    assert_eq!(key.expose_secret(), &[0x5A; 32]);
+}
+
+#[test]
+fn dynamic_downcast_wrong_type_returns_secret_unchanged() {
+    use std::any::Any;
+
+    let erased: Dynamic<dyn Any + Send> = Dynamic::new_boxed(Box::new(vec![1u8, 2, 3]));
+    let erased = erased.downcast::<String>().unwrap_err();
+    assert!(!erased.is::<String>());
+    let bytes: Dynamic<Vec<u8>> = erased.downcast().unwrap();
+    assert_eq!(bytes.expose_secret(), &[1, 2, 3]);
+}
@@ -732,35 +732,35 @@ fn fixed_zeroize_now_empty() {
 // from_hex() and from_base64url() constructors
 // ──────────────────────────────────────────────────────────────
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_hex_valid() {
     let key = Fixed::<[u8; 4]>::from_hex("deadbeef").unwrap();
     assert_eq!(key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_hex_uppercase() {
     let key = Fixed::<[u8; 4]>::from_hex("DEADBEEF").unwrap();
     assert_eq!(key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_hex_mixed_case() {
     let key = Fixed::<[u8; 4]>::from_hex("DeAdBeEf").unwrap();
     assert_eq!(key.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_hex_invalid_char() {
     let err = Fixed::<[u8; 4]>::from_hex("deadgbeef").unwrap_err();
     assert_eq!(err, "invalid hex string");
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_hex_wrong_length() {
     let err = Fixed::<[u8; 4]>::from_hex("deadbe").unwrap_err();
@@ -770,7 +770,7 @@ fn fixed_from_hex_wrong_length() {
     assert_eq!(err, "hex string length mismatch");
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_hex_empty() {
     let key = Fixed::<[u8; 0]>::from_hex("").unwrap();
@@ -778,7 +778,7 @@ fn fixed_from_hex_empty() {
     assert!(key.is_empty());
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_hex_32_bytes() {
     let hex = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
@@ -788,7 +788,7 @@ fn fixed_from_hex_32_bytes() {
     assert_eq!(key.expose_secret()[31], 0xef);
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_base64url_valid() {
     use base64::engine::general_purpose::URL_SAFE_NO_PAD;
@@ -800,7 +800,7 @@ fn fixed_from_base64url_valid() {
     assert_eq!(key.expose_secret(), &bytes);
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_base64url_wrong_length() {
     use base64::engine::general_purpose::URL_SAFE_NO_PAD;
@@ -812,14 +812,14 @@ fn fixed_from_base64url_wrong_length() {
     assert_eq!(err, "base64url string length mismatch");
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_base64url_invalid() {
     let err = Fixed::<[u8; 4]>::from_base64url("invalid!").unwrap_err();
     assert_eq!(err, "invalid base64url string");
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_base64url_empty() {
     let key = Fixed::<[u8; 0]>::from_base64url("").unwrap();
@@ -827,7 +827,7 @@ fn fixed_from_base64url_empty() {
     assert!(key.is_empty());
 }
 
-#[cfg(feature = "conversions")]
+#[cfg(all(feature = "conversions", feature = "alloc"))]
 #[test]
 fn fixed_from_hex_and_base64url_roundtrip() {
     use base64::engine::general_purpose::URL_SAFE_NO_PAD;
@@ -846,3 +846,778 @@ fn fixed_from_hex_and_base64url_roundtrip() {
     assert_eq!(from_b64.expose_secret(), &original);
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// write_into
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_write_into_chains_offsets() {
+    let header = Fixed::new([0x01u8, 0x02]);
+    let key = Fixed::new([0xAAu8; 4]);
+    let mut blob = [0u8; 6];
+
+    let offset = header.write_into(&mut blob, 0);
+    let offset = key.write_into(&mut blob, offset);
+
+    assert_eq!(offset, 6);
+    assert_eq!(blob, [0x01, 0x02, 0xAA, 0xAA, 0xAA, 0xAA]);
+}
+
+#[test]
+fn fixed_write_into_exact_fit_at_end() {
+    let key = Fixed::new([9u8; 3]);
+    let mut buf = [0u8; 5];
+    assert_eq!(key.write_into(&mut buf, 2), 5);
+    assert_eq!(buf, [0, 0, 9, 9, 9]);
+}
+
+#[test]
+#[should_panic(expected = "destination buffer too small")]
+fn fixed_write_into_overflow_panics() {
+    let key = Fixed::new([1u8; 4]);
+    let mut buf = [0u8; 4];
+    key.write_into(&mut buf, 1);
+}
+
+#[test]
+#[should_panic(expected = "destination buffer too small")]
+fn fixed_write_into_offset_overflow_panics() {
+    let key = Fixed::new([1u8; 4]);
+    let mut buf = [0u8; 4];
+    key.write_into(&mut buf, usize::MAX);
+}
+
+// ──────────────────────────────────────────────────────────────
+// generate_random_with (seeded, feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "rand")]
+#[test]
+fn fixed_generate_random_with_seed_is_reproducible() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let a = Fixed::<[u8; 32]>::generate_random_with(&mut StdRng::seed_from_u64(42));
+    let b = Fixed::<[u8; 32]>::generate_random_with(&mut StdRng::seed_from_u64(42));
+    assert_eq!(a.expose_secret(), b.expose_secret());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn fixed_generate_random_with_advances_rng() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = Fixed::<[u8; 32]>::generate_random_with(&mut rng);
+    let b = Fixed::<[u8; 32]>::generate_random_with(&mut rng);
+    assert_ne!(a.expose_secret(), b.expose_secret());
+
+    let other = Fixed::<[u8; 32]>::generate_random_with(&mut StdRng::seed_from_u64(43));
+    assert_ne!(a.expose_secret(), other.expose_secret());
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_eq_slice (feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_eq_slice_matches_runtime_input() {
+    let key = Fixed::new([0xde, 0xad, 0xbe, 0xef]);
+    let parsed: &[u8] = &[0xde, 0xad, 0xbe, 0xef]; // runtime-length input
+
+    assert!(key.ct_eq_slice(parsed));
+    assert!(!key.ct_eq_slice(&[0xde, 0xad, 0xbe, 0xee]));
+    assert!(!key.ct_eq_slice(&[0x00, 0xad, 0xbe, 0xef]));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_eq_slice_length_mismatch() {
+    let key = Fixed::new([1u8; 4]);
+
+    assert!(!key.ct_eq_slice(&[1, 1, 1]));
+    assert!(!key.ct_eq_slice(&[1, 1, 1, 1, 1]));
+    assert!(!key.ct_eq_slice(&[]));
+    assert!(Fixed::new([0u8; 0]).ct_eq_slice(&[]));
+}
+
+// ──────────────────────────────────────────────────────────────
+// const construction — static keys and test vectors
+// ──────────────────────────────────────────────────────────────
+
+static STATIC_KEY: Fixed<[u8; 32]> = Fixed::new([0x11; 32]);
+static STATIC_VECTOR: Fixed<[u8; 4]> = Fixed::from_array([0xde, 0xad, 0xbe, 0xef]);
+const CONST_KEY: Fixed<[u8; 16]> = Fixed::from_array([0x22; 16]);
+
+// Evaluated entirely at compile time — a failure here is a build error
+const _: () = {
+    assert!(CONST_KEY.len() == 16);
+    assert!(!CONST_KEY.is_empty());
+    assert!(CONST_KEY.expose_secret()[0] == 0x22);
+};
+
+#[test]
+fn fixed_static_and_const_construction() {
+    assert_eq!(STATIC_KEY.expose_secret(), &[0x11; 32]);
+    assert_eq!(STATIC_VECTOR.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(CONST_KEY.expose_secret(), &[0x22; 16]);
+    assert_eq!(format!("{STATIC_KEY:?}"), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// generate_random_below (rejection sampling, feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "rand")]
+#[test]
+fn fixed_generate_random_below_small_bound_is_uniform() {
+    let bound = [0x00, 0x05];
+    let mut counts = [0u32; 5];
+    for _ in 0..5_000 {
+        let n = Fixed::<[u8; 2]>::generate_random_below(&bound);
+        let v = u16::from_be_bytes(*n.expose_secret());
+        assert!(v < 5);
+        counts[v as usize] += 1;
+    }
+    // Expected 1000 each (σ ≈ 28); a ±30% window never trips by chance
+    for c in counts {
+        assert!((700..=1300).contains(&c), "skewed counts: {counts:?}");
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn fixed_generate_random_below_one_is_always_zero() {
+    for _ in 0..32 {
+        let n = Fixed::<[u8; 8]>::generate_random_below(&[0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(n.expose_secret(), &[0u8; 8]);
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn fixed_generate_random_below_multi_byte_bound() {
+    let bound = [0x01, 0x00, 0x00]; // 65536 — top byte masked to 1 bit
+    let mut saw_high = false;
+    for _ in 0..256 {
+        let n = Fixed::<[u8; 3]>::generate_random_below(&bound);
+        assert!(n.expose_secret() < &bound);
+        assert_eq!(n.expose_secret()[0], 0);
+        saw_high |= n.expose_secret()[1] >= 0x80;
+    }
+    assert!(saw_high, "lower bytes should span the full range");
+
+    let max = [0xFFu8; 16];
+    assert!(Fixed::<[u8; 16]>::generate_random_below(&max).expose_secret() < &max);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic(expected = "bound must be non-zero")]
+fn fixed_generate_random_below_zero_bound_panics() {
+    let _ = Fixed::<[u8; 4]>::generate_random_below(&[0u8; 4]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_cmp — constant-time big-endian ordering (feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_cmp_adjacent_values() {
+    use core::cmp::Ordering;
+
+    for n in [0u32, 1, 0xFF, 0x100, 0xFFFF, 0x0001_0000, 0x7FFF_FFFF, u32::MAX - 1] {
+        let a = Fixed::new(n.to_be_bytes());
+        let b = Fixed::new((n + 1).to_be_bytes());
+        assert_eq!(a.ct_cmp(&b), Ordering::Less, "{n} vs {}", n + 1);
+        assert_eq!(b.ct_cmp(&a), Ordering::Greater);
+        assert_eq!(a.ct_cmp(&a.clone()), Ordering::Equal);
+    }
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_cmp_matches_integer_order() {
+    let values = [0u64, 1, 2, 255, 256, 65_535, 1 << 40, u64::MAX];
+    for &x in &values {
+        for &y in &values {
+            let got = Fixed::new(x.to_be_bytes()).ct_cmp(&Fixed::new(y.to_be_bytes()));
+            assert_eq!(got, x.cmp(&y), "{x} vs {y}");
+        }
+    }
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_cmp_most_significant_byte_wins() {
+    use core::cmp::Ordering;
+
+    let a = Fixed::new([0x02, 0x00, 0x00, 0x00]);
+    let b = Fixed::new([0x01, 0xFF, 0xFF, 0xFF]);
+    assert_eq!(a.ct_cmp(&b), Ordering::Greater);
+
+    let empty = Fixed::new([0u8; 0]);
+    assert_eq!(empty.ct_cmp(&Fixed::new([])), Ordering::Equal);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Custom `#[derive(Zeroize)]` inner types (feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[derive(Clone, zeroize::Zeroize)]
+struct KeySchedule {
+    round_keys: [u64; 4],
+    counter: u32,
+}
+
+#[cfg(feature = "zeroize")]
+#[derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop)]
+struct WipedOnDrop([u8; 16]);
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_custom_zeroize_struct_zeroize_now() {
+    let mut ks = Fixed::new(KeySchedule {
+        round_keys: [u64::MAX; 4],
+        counter: 7,
+    });
+    ks.zeroize_now();
+    assert_eq!(ks.expose_secret().round_keys, [0; 4]);
+    assert_eq!(ks.expose_secret().counter, 0);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_custom_zeroize_struct_trait_impls() {
+    use zeroize::{Zeroize, ZeroizeOnDrop};
+
+    fn wipe_generic<T: Zeroize>(value: &mut T) {
+        value.zeroize();
+    }
+    fn assert_zod<T: ZeroizeOnDrop>() {}
+
+    let mut ks = Fixed::new(KeySchedule {
+        round_keys: [1, 2, 3, 4],
+        counter: 1,
+    });
+    let copy = ks.clone();
+    wipe_generic(&mut ks);
+    assert_eq!(ks.expose_secret().round_keys, [0; 4]);
+    assert_eq!(copy.expose_secret().round_keys, [1, 2, 3, 4]); // clone is independent
+
+    // Only an inner type that wipes itself makes `Fixed<T>` `ZeroizeOnDrop`
+    assert_zod::<Fixed<WipedOnDrop>>();
+    let wiped = Fixed::new(WipedOnDrop([0xAA; 16]));
+    assert_eq!(wiped.expose_secret().0, [0xAA; 16]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// fill — overwrite with a constant
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_fill_sets_every_byte() {
+    let mut key = Fixed::new([0x12u8; 32]);
+    key.fill(0xFF);
+    assert!(key.expose_secret().iter().all(|&b| b == 0xFF));
+    key.fill(0x00);
+    assert_eq!(key.expose_secret(), &[0u8; 32]);
+
+    let mut empty = Fixed::new([0u8; 0]);
+    empty.fill(0xAA);
+    assert!(empty.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// increment — counter-mode nonces
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_increment_be_carries_across_bytes() {
+    let mut ctr = Fixed::new([0x00, 0x01, 0xFF, 0xFF]);
+    ctr.increment_be();
+    assert_eq!(ctr.expose_secret(), &[0x00, 0x02, 0x00, 0x00]);
+    ctr.increment_be();
+    assert_eq!(ctr.expose_secret(), &[0x00, 0x02, 0x00, 0x01]);
+}
+
+#[test]
+fn fixed_increment_le_carries_across_bytes() {
+    let mut ctr = Fixed::new([0xFF, 0xFF, 0x01, 0x00]);
+    ctr.increment_le();
+    assert_eq!(ctr.expose_secret(), &[0x00, 0x00, 0x02, 0x00]);
+}
+
+#[test]
+fn fixed_increment_matches_integer_arithmetic() {
+    let mut be = Fixed::new(0x00FF_FFFF_u32.to_be_bytes());
+    let mut le = Fixed::new(0x00FF_FFFF_u32.to_le_bytes());
+    be.increment_be();
+    le.increment_le();
+    assert_eq!(u32::from_be_bytes(*be.expose_secret()), 0x0100_0000);
+    assert_eq!(u32::from_le_bytes(*le.expose_secret()), 0x0100_0000);
+}
+
+#[test]
+fn fixed_increment_wraps_at_max() {
+    let mut be = Fixed::new([0xFFu8; 12]);
+    be.increment_be();
+    assert_eq!(be.expose_secret(), &[0u8; 12]);
+
+    let mut le = Fixed::new([0xFFu8; 12]);
+    le.increment_le();
+    assert_eq!(le.expose_secret(), &[0u8; 12]);
+}
+
+#[test]
+fn fixed_checked_increment_be_refuses_to_wrap() {
+    let mut ctr = Fixed::new([0xFF, 0xFE]);
+    assert!(ctr.checked_increment_be());
+    assert_eq!(ctr.expose_secret(), &[0xFF, 0xFF]);
+    assert!(!ctr.checked_increment_be());
+    assert_eq!(ctr.expose_secret(), &[0xFF, 0xFF]);
+}
+
+#[test]
+fn fixed_add_be_carries_across_many_bytes() {
+    let mut ctr = Fixed::new([0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF]);
+    assert!(!ctr.add_be(1));
+    assert_eq!(ctr.expose_secret(), &[0x00, 0x01, 0x00, 0x00, 0x00, 0x00]);
+
+    let mut ctr = Fixed::new([0x00; 12]);
+    assert!(!ctr.add_be(u64::MAX));
+    assert!(!ctr.add_be(u64::MAX));
+    // 2 * (2^64 - 1) = 0x1_FFFF_FFFF_FFFF_FFFE
+    assert_eq!(
+        ctr.expose_secret(),
+        &[0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]
+    );
+}
+
+#[test]
+fn fixed_add_be_matches_integer_arithmetic() {
+    let start = 0x0000_00FF_FFFF_FF00_u64;
+    for addend in [0, 1, 0xFF, 0x100, 0xDEAD_BEEF, 0x00FF_FF00_0000_0000] {
+        let mut ctr = Fixed::new(u128::from(start).to_be_bytes());
+        assert!(!ctr.add_be(addend));
+        let expected = u128::from(start) + u128::from(addend);
+        assert_eq!(u128::from_be_bytes(*ctr.expose_secret()), expected);
+    }
+}
+
+#[test]
+fn fixed_add_be_reports_overflow_without_wrapping() {
+    let mut ctr = Fixed::new([0xFF, 0xFF, 0xFF, 0xF0]);
+    assert!(!ctr.add_be(0x0F));
+    assert!(ctr.add_be(1));
+    assert_eq!(ctr.expose_secret(), &[0xFF; 4]);
+
+    // Addend wider than the array
+    let mut short = Fixed::new([0x00, 0x00]);
+    assert!(short.add_be(0x1_0000));
+    assert_eq!(short.expose_secret(), &[0x00, 0x00]);
+    assert!(!short.add_be(0xFFFF));
+    assert_eq!(short.expose_secret(), &[0xFF, 0xFF]);
+
+    let mut empty = Fixed::new([0u8; 0]);
+    assert!(!empty.add_be(0));
+    assert!(empty.add_be(1));
+}
+
+#[test]
+fn fixed_sub_be_borrows_and_reports_underflow() {
+    let mut ctr = Fixed::new([0x01, 0x00, 0x00, 0x00]);
+    assert!(!ctr.sub_be(1));
+    assert_eq!(ctr.expose_secret(), &[0x00, 0xFF, 0xFF, 0xFF]);
+
+    let mut ctr = Fixed::new(0x1234_5678_9ABC_u64.to_be_bytes());
+    assert!(!ctr.sub_be(0x0234_0000_9ABD));
+    assert_eq!(u64::from_be_bytes(*ctr.expose_secret()), 0x1000_5677_FFFF);
+
+    let mut low = Fixed::new([0x00, 0x05]);
+    assert!(low.sub_be(6));
+    assert_eq!(low.expose_secret(), &[0x00, 0x05]);
+    assert!(low.sub_be(0x1_0000));
+    assert_eq!(low.expose_secret(), &[0x00, 0x05]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// expose_slice / expose_slice_mut
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_expose_slice_views_same_bytes() {
+    fn takes_slice(bytes: &[u8]) -> usize {
+        bytes.len()
+    }
+
+    let mut key = Fixed::new([9u8; 16]);
+    assert_eq!(takes_slice(key.expose_slice()), 16);
+    assert_eq!(key.expose_slice(), key.expose_secret().as_slice());
+
+    key.expose_slice_mut()[15] = 0;
+    assert_eq!(key.expose_secret()[15], 0);
+    assert!(Fixed::new([0u8; 0]).expose_slice().is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// sub — borrowed fixed-size sub-arrays
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_sub_borrows_named_regions() {
+    let key = Fixed::new(core::array::from_fn::<u8, 32, _>(|i| i as u8));
+
+    let enc: &[u8; 16] = key.sub::<0, 16>();
+    let mac: &[u8; 16] = key.sub::<16, 16>();
+    assert_eq!(enc[..], key.expose_secret()[..16]);
+    assert_eq!(mac[..], key.expose_secret()[16..]);
+    assert!(core::ptr::eq(mac.as_ptr(), key.expose_secret()[16..].as_ptr()));
+
+    assert_eq!(key.sub::<0, 32>(), key.expose_secret());
+    assert!(key.sub::<32, 0>().is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_eq_any — constant-time membership
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_eq_any_finds_match_at_any_position() {
+    let valid: Vec<Fixed<[u8; 16]>> = (0u8..5).map(|i| Fixed::new([i; 16])).collect();
+    for i in 0u8..5 {
+        assert!(Fixed::new([i; 16]).ct_eq_any(&valid));
+    }
+    assert!(!Fixed::new([9u8; 16]).ct_eq_any(&valid));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_eq_any_empty_and_near_miss() {
+    let token = Fixed::new([7u8, 7, 7, 7]);
+    assert!(!token.ct_eq_any(&[]));
+    assert!(!token.ct_eq_any(&[Fixed::new([7, 7, 7, 6]), Fixed::new([6, 7, 7, 7])]));
+}
+
+// ──────────────────────────────────────────────────────────────
+// into_zeroizing — bridge to zeroize::Zeroizing
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_into_zeroizing_preserves_bytes() {
+    let key = Fixed::new([0x5Au8; 32]);
+    let wrapped = key.into_zeroizing();
+    assert_eq!(*wrapped, [0x5Au8; 32]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_into_zeroizing_wipes_on_drop() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct Probe(Rc<Cell<bool>>);
+    impl zeroize::Zeroize for Probe {
+        fn zeroize(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let wiped = Rc::new(Cell::new(false));
+    let wrapped = Fixed::new(Probe(wiped.clone())).into_zeroizing();
+    assert!(!wiped.get());
+    drop(wrapped);
+    assert!(wiped.get());
+}
+
+// ──────────────────────────────────────────────────────────────
+// try_new — validate then wrap
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+fn reject_all_zero(key: &[u8; 32]) -> Result<(), &'static str> {
+    if key.iter().all(|&b| b == 0) {
+        Err("all-zero key")
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_try_new_accepts_valid_key() {
+    let mut bytes = [0u8; 32];
+    bytes[31] = 1;
+    let key = Fixed::try_new(bytes, reject_all_zero).unwrap();
+    assert_eq!(key.expose_secret(), &bytes);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_try_new_rejects_all_zero_key() {
+    assert_eq!(
+        Fixed::try_new([0u8; 32], reject_all_zero).unwrap_err(),
+        "all-zero key"
+    );
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_try_new_wipes_rejected_value() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct Probe(Rc<Cell<bool>>);
+    impl zeroize::Zeroize for Probe {
+        fn zeroize(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let wiped = Rc::new(Cell::new(false));
+    let rejected = Fixed::try_new(Probe(wiped.clone()), |_| Err::<(), _>("nope"));
+    assert!(rejected.is_err());
+    assert!(wiped.get());
+
+    let wiped = Rc::new(Cell::new(false));
+    let accepted = Fixed::try_new(Probe(wiped.clone()), |_| Ok::<(), ()>(()));
+    assert!(accepted.is_ok());
+    assert!(!wiped.get());
+}
+
+// ──────────────────────────────────────────────────────────────
+// map_bytes — element-wise derivation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_map_bytes_complement() {
+    let key = Fixed::new([0x00u8, 0xFF, 0x5A, 0xA5]);
+    let complement = key.map_bytes(|b| !b);
+    assert_eq!(complement.expose_secret(), &[0xFF, 0x00, 0xA5, 0x5A]);
+
+    // Source untouched; complement of the complement is the original
+    assert_eq!(key.expose_secret(), &[0x00, 0xFF, 0x5A, 0xA5]);
+    assert_eq!(complement.map_bytes(|b| !b).expose_secret(), key.expose_secret());
+}
+
+#[test]
+fn fixed_map_bytes_preserves_position() {
+    let key = Fixed::new([1u8, 2, 3, 4, 5, 6, 7, 8]);
+    let xored = key.map_bytes(|b| b ^ 0x36);
+    for (i, &b) in xored.expose_secret().iter().enumerate() {
+        assert_eq!(b, (i as u8 + 1) ^ 0x36);
+    }
+}
+
+// ──────────────────────────────────────────────────────────────
+// to_dynamic_copy — protected heap copy
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "alloc")]
+#[test]
+fn fixed_to_dynamic_copy_is_independent_and_redacted() {
+    let key = Fixed::new([0x42u8; 32]);
+    let mut copy = key.to_dynamic_copy();
+    assert_eq!(copy.expose_secret().as_slice(), key.expose_secret());
+
+    copy.expose_secret_mut()[0] = 0;
+    assert_eq!(key.expose_secret()[0], 0x42);
+    assert_eq!(format!("{copy:?}"), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// Clone independence — wiping one copy never touches another
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_clone_wipe_leaves_original_intact() {
+    let original = Fixed::new([0x77u8; 32]);
+    let mut copy = original.clone();
+    assert_ne!(
+        original.expose_secret().as_ptr(),
+        copy.expose_secret().as_ptr()
+    );
+
+    copy.zeroize_now();
+    assert_eq!(copy.expose_secret(), &[0u8; 32]);
+    assert_eq!(original.expose_secret(), &[0x77u8; 32]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_clone_drop_wipes_only_its_own_copy() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // Inner type that wipes itself on drop and records which copy was wiped
+    struct Tracked {
+        id: u32,
+        bytes: [u8; 32],
+        wiped: Rc<RefCell<Vec<u32>>>,
+    }
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            Tracked {
+                id: self.id + 1,
+                bytes: self.bytes,
+                wiped: self.wiped.clone(),
+            }
+        }
+    }
+    impl zeroize::Zeroize for Tracked {
+        fn zeroize(&mut self) {
+            self.bytes.zeroize();
+            self.wiped.borrow_mut().push(self.id);
+        }
+    }
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            zeroize::Zeroize::zeroize(self);
+        }
+    }
+
+    let wiped = Rc::new(RefCell::new(Vec::new()));
+    let original = Fixed::new(Tracked {
+        id: 0,
+        bytes: [0x55; 32],
+        wiped: wiped.clone(),
+    });
+    let copy = original.clone();
+
+    drop(copy);
+    assert_eq!(*wiped.borrow(), [1]);
+    assert_eq!(original.expose_secret().bytes, [0x55; 32]);
+
+    drop(original);
+    assert_eq!(*wiped.borrow(), [1, 0]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// ct_eq_masked — compare selected bytes only
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_eq_masked_ignores_masked_off_bytes() {
+    let mut mask = [0xFFu8; 32];
+    mask[0] = 0; // version byte
+    mask[31] = 0; // flags byte
+
+    let mut a = [0x11u8; 32];
+    let mut b = [0x11u8; 32];
+    a[0] = 1;
+    b[0] = 2;
+    a[31] = 0x80;
+    b[31] = 0x00;
+    assert!(Fixed::new(a).ct_eq_masked(&Fixed::new(b), &mask));
+    assert!(!Fixed::new(a).ct_eq(&Fixed::new(b)));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_ct_eq_masked_detects_selected_difference() {
+    let a = Fixed::new([0u8; 8]);
+    for i in 0..8 {
+        let mut bytes = [0u8; 8];
+        bytes[i] = 1;
+        let b = Fixed::new(bytes);
+        assert!(!a.ct_eq_masked(&b, &[1; 8]), "difference at {i}");
+
+        let mut mask = [1u8; 8];
+        mask[i] = 0;
+        assert!(a.ct_eq_masked(&b, &mask));
+    }
+    assert!(a.ct_eq_masked(&Fixed::new([0xFF; 8]), &[0; 8]));
+}
+
+// ──────────────────────────────────────────────────────────────
+// Default — all-zero placeholder
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_default_is_all_zero() {
+    let placeholder = Fixed::<[u8; 16]>::default();
+    assert_eq!(placeholder.expose_secret(), &[0u8; 16]);
+    assert_eq!(format!("{placeholder:?}"), "[REDACTED]");
+}
+
+#[test]
+fn fixed_default_enables_struct_derive() {
+    #[derive(Default)]
+    struct Session {
+        key: Fixed<[u8; 32]>,
+        nonce: Fixed<[u8; 12]>,
+        counter: u64,
+    }
+
+    let mut session = Session::default();
+    assert_eq!(session.key.expose_secret(), &[0u8; 32]);
+    assert_eq!(session.nonce.len(), 12);
+    assert_eq!(session.counter, 0);
+
+    session.key = Fixed::new([0xAB; 32]);
+    assert_eq!(session.key.expose_secret()[0], 0xAB);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Bulk wrapping — from_arrays / SecretWrapExt
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_wrap_fixed_iterator_adapter() {
+    use secure_gate::SecretWrapExt;
+
+    let raw = [[1u8; 8], [2u8; 8], [3u8; 8]];
+    let mut wrapped = raw.into_iter().wrap_fixed();
+    assert_eq!(wrapped.size_hint(), (3, Some(3)));
+
+    let first: Fixed<[u8; 8]> = wrapped.next().unwrap();
+    assert_eq!(first.expose_secret(), &[1u8; 8]);
+    let rest: Vec<_> = wrapped.collect();
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest[1].expose_secret(), &[3u8; 8]);
+    assert_eq!(format!("{:?}", rest[0]), "[REDACTED]");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn fixed_from_arrays_wraps_batch_in_order() {
+    let batch: Vec<[u8; 4]> = (0u8..5).map(|i| [i; 4]).collect();
+    let keyring = Fixed::from_arrays(&batch);
+    assert_eq!(keyring.len(), 5);
+    for (i, key) in keyring.iter().enumerate() {
+        assert_eq!(key.expose_secret(), &[i as u8; 4]);
+    }
+    assert!(Fixed::<[u8; 4]>::from_arrays(&[]).is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// take_and_wipe — read out, wipe in place (zeroize)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_take_and_wipe_returns_bytes_and_zeroes_self() {
+    let mut key = Fixed::new([0x5Cu8; 32]);
+    let bytes = key.take_and_wipe();
+    assert_eq!(bytes, [0x5C; 32]);
+    assert_eq!(key.expose_secret(), &[0u8; 32]);
+
+    // Still usable: refill and take again
+    key.expose_secret_mut().copy_from_slice(&[1u8; 32]);
+    assert_eq!(key.take_and_wipe(), [1u8; 32]);
+    assert_eq!(key.expose_secret(), &[0u8; 32]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn fixed_take_and_wipe_empty_array() {
+    let mut empty = Fixed::new([0u8; 0]);
+    assert_eq!(empty.take_and_wipe(), []);
+}
@@ -3,6 +3,8 @@
 // ==========================================================================
 // Core integration tests — pure v0.6.0 API
 
+#![cfg(feature = "alloc")]
+
 use secure_gate::{Dynamic, DynamicNoClone, Fixed};
 
 #[test]
@@ -0,0 +1,137 @@
+// ==========================================================================
+// tests/interop_tests.rs
+// ==========================================================================
+// Testing for third-party ecosystem bridges
+
+#[cfg(feature = "secrecy")]
+use secure_gate::Dynamic;
+
+// ──────────────────────────────────────────────────────────────
+// secrecy
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "secrecy")]
+#[test]
+fn dynamic_into_secrecy_moves_box() {
+    use secrecy::ExposeSecret;
+
+    let secret = Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3]);
+    let ptr = secret.expose_secret().as_ptr();
+
+    let boxed = secret.into_secrecy();
+    assert_eq!(boxed.expose_secret(), &[1, 2, 3]);
+    assert_eq!(boxed.expose_secret().as_ptr(), ptr); // no copy
+}
+
+#[cfg(feature = "secrecy")]
+#[test]
+fn dynamic_into_secret_box_via_from() {
+    use secrecy::{ExposeSecret, SecretBox};
+
+    let secret: Dynamic<String> = "hunter2".into();
+    let boxed: SecretBox<String> = secret.into();
+    assert_eq!(boxed.expose_secret(), "hunter2");
+}
+
+#[cfg(feature = "secrecy")]
+#[test]
+fn dynamic_into_secrecy_unsized() {
+    use secrecy::ExposeSecret;
+
+    let secret = Dynamic::<[u8]>::new_boxed(vec![7u8; 4].into_boxed_slice());
+    let boxed: secrecy::SecretSlice<u8> = secret.into_secrecy();
+    assert_eq!(boxed.expose_secret(), &[7, 7, 7, 7]);
+}
+
+#[cfg(feature = "secrecy")]
+fn secret_len<S: secrecy::ExposeSecret<T>, T: AsRef<[u8]> + ?Sized>(secret: &S) -> usize {
+    secret.expose_secret().as_ref().len()
+}
+
+#[cfg(feature = "secrecy")]
+#[test]
+fn fixed_and_dynamic_implement_expose_secret() {
+    let key = secure_gate::Fixed::new([0u8; 32]);
+    let pw: Dynamic<String> = "hunter2".into();
+    let bytes = Dynamic::<[u8]>::new_boxed(vec![1u8; 5].into_boxed_slice());
+
+    assert_eq!(secret_len(&key), 32);
+    assert_eq!(secret_len(&pw), 7);
+    assert_eq!(secret_len(&bytes), 5);
+}
+
+#[cfg(feature = "secrecy")]
+#[test]
+fn fixed_and_dynamic_implement_expose_secret_mut() {
+    fn zero_first<S: secrecy::ExposeSecretMut<[u8; 4]>>(secret: &mut S) {
+        secret.expose_secret_mut()[0] = 0;
+    }
+
+    let mut key = secure_gate::Fixed::new([9u8; 4]);
+    let mut boxed = Dynamic::<[u8; 4]>::new([9u8; 4]);
+    zero_first(&mut key);
+    zero_first(&mut boxed);
+
+    assert_eq!(key.expose_secret(), &[0, 9, 9, 9]);
+    assert_eq!(boxed.expose_secret(), &[0, 9, 9, 9]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// generic-array
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "generic-array")]
+#[allow(deprecated)] // generic-array 0.14.8+ deprecates itself; see src/interop.rs
+#[test]
+fn fixed_as_generic_array_borrows_in_place() {
+    use generic_array::{typenum::U32, GenericArray};
+    use secure_gate::Fixed;
+
+    let key = Fixed::new([0x42u8; 32]);
+    let ga: &GenericArray<u8, U32> = key.as_generic_array();
+    assert_eq!(ga.as_slice(), &[0x42u8; 32]);
+    assert_eq!(ga.as_ptr(), key.expose_secret().as_ptr()); // no copy
+}
+
+#[cfg(feature = "generic-array")]
+#[allow(deprecated)] // generic-array 0.14.8+ deprecates itself; see src/interop.rs
+#[test]
+fn fixed_from_generic_array_roundtrip() {
+    use generic_array::{typenum::U16, GenericArray};
+    use secure_gate::Fixed;
+
+    let ga: GenericArray<u8, U16> = GenericArray::clone_from_slice(&[9u8; 16]);
+    let key = Fixed::<[u8; 16]>::from_generic_array(ga);
+    assert_eq!(key.expose_secret(), &[9u8; 16]);
+
+    // Inferred from the expected type, as when calling RustCrypto APIs
+    fn takes_key(k: &GenericArray<u8, U16>) -> u8 {
+        k[0]
+    }
+    assert_eq!(takes_key(key.as_generic_array()), 9);
+}
+
+// ──────────────────────────────────────────────────────────────
+// bytes
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "bytes")]
+#[test]
+fn dynamic_into_bytes_shared_moves_buffer() {
+    let secret = secure_gate::Dynamic::<Vec<u8>>::new(vec![1u8, 2, 3, 4]);
+    let ptr = secret.expose_secret().as_ptr();
+
+    let shared = secret.into_bytes_shared();
+    assert_eq!(&shared[..], &[1, 2, 3, 4]);
+    assert_eq!(shared.as_ptr(), ptr); // no copy
+
+    let clone = shared.clone();
+    assert_eq!(clone.as_ptr(), ptr); // ref-counted, still the same buffer
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn dynamic_into_bytes_shared_empty() {
+    let secret = secure_gate::Dynamic::<Vec<u8>>::new(Vec::new());
+    assert!(secret.into_bytes_shared().is_empty());
+}
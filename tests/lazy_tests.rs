@@ -0,0 +1,83 @@
+// ==========================================================================
+// tests/lazy_tests.rs
+// ==========================================================================
+// Testing for lazily derived secrets
+
+#![cfg(feature = "alloc")]
+
+use secure_gate::LazySecret;
+use std::cell::Cell;
+
+// ──────────────────────────────────────────────────────────────
+// Initialization
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn lazy_initializer_runs_at_most_once() {
+    let calls = Cell::new(0);
+    let secret = LazySecret::new(|| {
+        calls.set(calls.get() + 1);
+        String::from("derived")
+    });
+    assert_eq!(calls.get(), 0);
+    assert!(!secret.is_initialized());
+
+    assert_eq!(secret.get_or_init().expose_secret(), "derived");
+    assert_eq!(secret.get_or_init().expose_secret(), "derived");
+    assert_eq!(calls.get(), 1);
+    assert!(secret.is_initialized());
+}
+
+#[test]
+fn lazy_never_accessed_never_runs() {
+    let ran = Cell::new(false);
+    let secret = LazySecret::new(|| {
+        ran.set(true);
+        vec![1u8]
+    });
+    drop(secret);
+    assert!(!ran.get());
+}
+
+#[test]
+fn lazy_debug_is_redacted() {
+    let secret = LazySecret::new(|| String::from("hunter2"));
+    assert_eq!(format!("{secret:?}"), "[REDACTED]");
+    let _ = secret.get_or_init();
+    assert_eq!(format!("{secret:?}"), "[REDACTED]");
+}
+
+#[test]
+fn lazy_fn_pointer_default_type() {
+    fn derive() -> Vec<u8> {
+        vec![7u8; 4]
+    }
+    let secret: LazySecret<Vec<u8>> = LazySecret::new(derive);
+    assert_eq!(secret.get_or_init().len(), 4);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Wipe on drop (feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn lazy_initialized_value_wiped_on_drop() {
+    use std::rc::Rc;
+
+    struct Probe(Rc<Cell<bool>>);
+    impl zeroize::Zeroize for Probe {
+        fn zeroize(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    let wiped = Rc::new(Cell::new(false));
+    let probe_flag = wiped.clone();
+    let secret = LazySecret::new(move || Probe(probe_flag));
+    let _ = secret.get_or_init();
+    assert!(!wiped.get());
+
+    drop(secret);
+    assert!(wiped.get());
+}
@@ -3,7 +3,7 @@
 // ==========================================================================
 // Comprehensive testing for all macros
 
-#![cfg(test)]
+#![cfg(feature = "alloc")]
 
 use secure_gate::{dynamic_alias, fixed_alias};
 
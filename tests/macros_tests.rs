@@ -3,7 +3,7 @@
 // ==========================================================================
 // Comprehensive testing for all macros
 
-#![cfg(test)]
+#![cfg(feature = "alloc")]
 
 use secure_gate::{dynamic_alias, fixed_alias};
 
@@ -664,3 +664,188 @@ fn fixed_generic_alias_multiple_sizes() {
     assert_eq!(k128.len(), 128);
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// secret_struct! — redacted Debug and field-wise zeroize
+// ──────────────────────────────────────────────────────────────
+
+secure_gate::secret_struct! {
+    /// Test credentials
+    #[derive(Clone)]
+    pub struct Creds {
+        pub key: [u8; 32],
+        pub password: String,
+        pin: u32,
+    }
+}
+
+#[test]
+fn secret_struct_fields_are_wrapped() {
+    let creds = Creds::new([0x42; 32], "hunter2".into(), 1234);
+    assert_eq!(creds.key.expose_secret(), &[0x42; 32]);
+    assert_eq!(creds.password.expose_secret(), "hunter2");
+    assert_eq!(*creds.pin.expose_secret(), 1234);
+}
+
+#[test]
+fn secret_struct_debug_is_redacted() {
+    let creds = Creds::new([0x42; 32], "hunter2".into(), 1234);
+    assert_eq!(format!("{creds:?}"), "[REDACTED]");
+    assert_eq!(format!("{creds:#?}"), "[REDACTED]");
+    assert!(!format!("{creds:?}").contains("hunter2"));
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn secret_struct_zeroizes_each_field() {
+    use zeroize::Zeroize;
+
+    let mut creds = Creds::new([0x42; 32], "hunter2".into(), 1234);
+    creds.zeroize();
+    assert_eq!(creds.key.expose_secret(), &[0u8; 32]);
+    assert!(creds.password.expose_secret().is_empty());
+    assert_eq!(*creds.pin.expose_secret(), 0);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn secret_struct_is_zeroize_on_drop() {
+    fn assert_zod<T: zeroize::ZeroizeOnDrop>() {}
+    assert_zod::<Creds>();
+}
+
+// ──────────────────────────────────────────────────────────────
+// assert_secret_eq! — constant-time, redacted assertion
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn assert_secret_eq_passes_on_equal_secrets() {
+    use secure_gate::{assert_secret_eq, Dynamic, Fixed};
+
+    assert_secret_eq!(Fixed::new([7u8; 32]), Fixed::new([7u8; 32]));
+
+    let a = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    let b = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    assert_secret_eq!(a, b);
+    assert_secret_eq!(a, b, "with message {}", 1);
+
+    // Raw arrays go through SecureConversionsExt without an import
+    assert_secret_eq!([0xAAu8; 4], [0xAAu8; 4]);
+    // Operands are borrowed, not moved
+    assert_eq!(a.len(), 3);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn assert_secret_eq_failure_is_redacted() {
+    use secure_gate::{assert_secret_eq, Fixed};
+
+    let result = std::panic::catch_unwind(|| {
+        let key = Fixed::new(*b"hunter2!");
+        let other = Fixed::new(*b"hunter3!");
+        assert_secret_eq!(key, other, "slot {}", 5);
+    });
+    let payload = result.unwrap_err();
+    // Fully-literal messages are folded at compile time into a `&'static str` payload
+    let msg = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap();
+    assert_eq!(
+        msg,
+        "assert_secret_eq!(key, other) failed: [REDACTED] != [REDACTED]: slot 5"
+    );
+    assert!(!msg.contains("hunter"));
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+#[should_panic(expected = "[REDACTED] != [REDACTED]")]
+fn assert_secret_eq_panics_on_length_mismatch() {
+    let a = secure_gate::Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    let b = secure_gate::Dynamic::<Vec<u8>>::new(vec![1, 2]);
+    secure_gate::assert_secret_eq!(a, b);
+}
+
+// ──────────────────────────────────────────────────────────────
+// fixed_hex! — compile-time hex literals
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_hex_decodes_and_infers_length() {
+    let key = secure_gate::fixed_hex!("000102030405060708090a0b0c0d0e0f");
+    assert_eq!(key.len(), 16);
+    assert_eq!(key.expose_secret(), &core::array::from_fn::<u8, 16, _>(|i| i as u8));
+
+    let mixed: secure_gate::Fixed<[u8; 3]> = secure_gate::fixed_hex!("aBcDeF");
+    assert_eq!(mixed.expose_secret(), &[0xAB, 0xCD, 0xEF]);
+
+    let empty = secure_gate::fixed_hex!("");
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn fixed_hex_decodes_long_test_vector() {
+    // SHA-256("abc")
+    let digest = secure_gate::fixed_hex!(
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(digest.len(), 32);
+    assert_eq!(digest.expose_secret()[0], 0xBA);
+    assert_eq!(digest.expose_secret()[31], 0xAD);
+}
+
+// ──────────────────────────────────────────────────────────────
+// keyed_alias! — domain key newtypes
+// ──────────────────────────────────────────────────────────────
+
+secure_gate::keyed_alias!(pub Aes256Key, 32);
+secure_gate::keyed_alias!(NonceKey, 12);
+
+#[test]
+fn keyed_alias_basics() {
+    let key = Aes256Key::from_bytes([0x42; 32]);
+    assert_eq!(Aes256Key::LEN, 32);
+    assert_eq!(NonceKey::LEN, 12);
+    assert_eq!(key.expose_secret(), &[0x42; 32]);
+    assert_eq!(format!("{key:?}"), "[REDACTED]");
+
+    let inner: secure_gate::Fixed<[u8; 32]> = key.into_inner();
+    assert_eq!(inner.expose_secret(), &[0x42; 32]);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn keyed_alias_generate() {
+    let a = Aes256Key::generate();
+    let b = Aes256Key::generate();
+    assert_ne!(a.expose_secret(), b.expose_secret());
+    assert_eq!(NonceKey::generate().expose_secret().len(), 12);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn keyed_alias_ct_eq() {
+    let a = NonceKey::from_bytes([1; 12]);
+    assert!(a.ct_eq(&NonceKey::from_bytes([1; 12])));
+    assert!(!a.ct_eq(&NonceKey::from_bytes([2; 12])));
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+#[test]
+fn keyed_alias_to_hex_is_wrapped() {
+    let key = NonceKey::from_bytes([0xAB; 12]);
+    let hex: secure_gate::Dynamic<String> = key.to_hex();
+    assert_eq!(hex.expose_secret(), &"ab".repeat(12));
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn keyed_alias_zeroize() {
+    use zeroize::Zeroize;
+    let mut key = Aes256Key::from_bytes([0xFF; 32]);
+    key.zeroize();
+    assert_eq!(key.expose_secret(), &[0u8; 32]);
+}
@@ -3,6 +3,8 @@
 // ==========================================================================
 // Comprehensive testing for NoClone types
 
+#![cfg(feature = "alloc")]
+
 use secure_gate::{Dynamic, DynamicNoClone, Fixed, FixedNoClone};
 
 // ──────────────────────────────────────────────────────────────
@@ -3,6 +3,8 @@
 // ==========================================================================
 // Comprehensive testing for NoClone types
 
+#![cfg(feature = "alloc")]
+
 use secure_gate::{Dynamic, DynamicNoClone, Fixed, FixedNoClone};
 
 // ──────────────────────────────────────────────────────────────
@@ -788,3 +790,49 @@ fn dynamic_no_clone_vec_max_reasonable_size() {
     assert_eq!(data.expose_secret()[0], 42);
     assert_eq!(data.expose_secret()[1023], 42);
 }
+
+// ──────────────────────────────────────────────────────────────
+// expose_slice / expose_slice_mut
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_no_clone_expose_slice_views_same_bytes() {
+    let mut key: FixedNoClone<[u8; 4]> = Fixed::new([1u8, 2, 3, 4]).no_clone();
+    assert_eq!(key.expose_slice(), key.expose_secret().as_slice());
+
+    key.expose_slice_mut()[0] = 0xFF;
+    assert_eq!(key.expose_secret(), &[0xFF, 2, 3, 4]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// shrink_and_wipe (feature-gated)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_no_clone_vec_shrink_and_wipe() {
+    let mut v = Vec::with_capacity(128);
+    v.extend_from_slice(&[0xAB; 10]);
+    let mut secret = DynamicNoClone::new(Box::new(v));
+
+    secret.shrink_and_wipe();
+    assert_eq!(secret.expose_secret().capacity(), 10);
+    assert_eq!(secret.expose_secret(), &[0xAB; 10]);
+
+    // Already tight: no-op
+    let ptr = secret.expose_secret().as_ptr();
+    secret.shrink_and_wipe();
+    assert_eq!(secret.expose_secret().as_ptr(), ptr);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn dynamic_no_clone_string_shrink_and_wipe() {
+    let mut s = String::with_capacity(64);
+    s.push_str("hunter2");
+    let mut secret = DynamicNoClone::new(Box::new(s));
+
+    secret.shrink_and_wipe();
+    assert_eq!(secret.expose_secret().capacity(), 7);
+    assert_eq!(secret.expose_secret(), "hunter2");
+}
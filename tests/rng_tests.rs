@@ -652,3 +652,429 @@ fn dynamic_rng_zeroize_on_drop() {
     assert_eq!(original_len, 64);
 }
 
+
+// ──────────────────────────────────────────────────────────────
+// Fallible generation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn try_generate_succeeds_on_supported_platforms() {
+    let fixed = FixedRng::<32>::try_generate().expect("OS RNG available");
+    assert_eq!(fixed.len(), 32);
+
+    let dynamic = DynamicRng::try_generate(48).expect("OS RNG available");
+    assert_eq!(dynamic.len(), 48);
+}
+
+#[test]
+fn rng_unavailable_error_is_actionable() {
+    assert!(secure_gate::rng::RNG_UNAVAILABLE.contains("wasm-js"));
+    assert!(secure_gate::rng::RNG_UNAVAILABLE.contains("getrandom_backend"));
+}
+
+// ──────────────────────────────────────────────────────────────
+// UUID v4
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn rng_uuid_v4_format() {
+    let id = DynamicRng::rng_uuid_v4();
+    let s = id.expose_secret();
+
+    assert_eq!(s.len(), 36);
+    for (i, c) in s.char_indices() {
+        if matches!(i, 8 | 13 | 18 | 23) {
+            assert_eq!(c, '-');
+        } else {
+            assert!(c.is_ascii_hexdigit() && !c.is_ascii_uppercase());
+        }
+    }
+    assert_eq!(&s[14..15], "4");
+    assert!(matches!(&s[19..20], "8" | "9" | "a" | "b"));
+}
+
+#[test]
+fn rng_uuid_v4_is_fresh_and_redacted() {
+    let a = DynamicRng::rng_uuid_v4();
+    let b = DynamicRng::rng_uuid_v4();
+    assert_ne!(a.expose_secret(), b.expose_secret());
+    assert_eq!(format!("{a:?}"), "[REDACTED]");
+}
+
+// ──────────────────────────────────────────────────────────────
+// DynamicRng::generate_hex / generate_base64url
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_rng_generate_hex_length_and_alphabet() {
+    let token = DynamicRng::generate_hex(20);
+    assert_eq!(token.len(), 40);
+    assert!(token
+        .expose_secret()
+        .chars()
+        .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+    assert_ne!(token.expose_secret(), DynamicRng::generate_hex(20).expose_secret());
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_rng_generate_base64url_length_and_alphabet() {
+    let token = DynamicRng::generate_base64url(32);
+    assert_eq!(token.len(), 43);
+    assert!(token
+        .expose_secret()
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    assert_eq!(format!("{token:?}"), "[REDACTED]");
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn dynamic_rng_generate_encoded_zero_length() {
+    assert!(DynamicRng::generate_hex(0).is_empty());
+    assert!(DynamicRng::generate_base64url(0).is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// DynamicRng::try_into_fixed
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn dynamic_rng_try_into_fixed_matching_length() {
+    let rng = DynamicRng::generate(32);
+    let expected = rng.expose_secret().to_vec();
+
+    let key: Fixed<[u8; 32]> = rng.try_into_fixed().unwrap();
+    assert_eq!(key.expose_secret().as_slice(), expected.as_slice());
+}
+
+#[test]
+fn dynamic_rng_try_into_fixed_mismatch_returns_original() {
+    let rng = DynamicRng::generate(24);
+    let expected = rng.expose_secret().to_vec();
+
+    let back = rng.try_into_fixed::<32>().unwrap_err();
+    assert_eq!(back.expose_secret(), expected.as_slice());
+}
+
+#[test]
+fn dynamic_rng_try_into_fixed_empty() {
+    let key = DynamicRng::generate(0).try_into_fixed::<0>().unwrap();
+    assert!(key.is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// SharedRng — one generator shared across threads
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "shared-rng")]
+#[test]
+fn shared_rng_is_send_sync_and_redacted() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<secure_gate::rng::SharedRng>();
+
+    let rng = secure_gate::rng::SharedRng::new();
+    assert_eq!(format!("{rng:?}"), "[REDACTED]");
+}
+
+#[cfg(feature = "shared-rng")]
+#[test]
+fn shared_rng_generates_fresh_values() {
+    let rng = secure_gate::rng::SharedRng::new();
+
+    let a: FixedRng<32> = rng.generate();
+    let b: FixedRng<32> = rng.generate();
+    assert_ne!(a.expose_secret(), b.expose_secret());
+
+    let d = rng.generate_dynamic(48);
+    assert_eq!(d.len(), 48);
+    assert!(rng.generate_dynamic(0).is_empty());
+}
+
+#[cfg(feature = "shared-rng")]
+#[test]
+fn shared_rng_across_threads_and_reseed() {
+    use std::{collections::HashSet, sync::Arc, thread};
+
+    let rng = Arc::new(secure_gate::rng::SharedRng::new());
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let rng = Arc::clone(&rng);
+            thread::spawn(move || {
+                (0..64)
+                    .map(|_| *rng.generate::<16>().expose_secret())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    for h in handles {
+        for token in h.join().unwrap() {
+            assert!(seen.insert(token), "duplicate token across threads");
+        }
+    }
+
+    rng.reseed();
+    assert!(seen.insert(*rng.generate::<16>().expose_secret()));
+}
+
+// ──────────────────────────────────────────────────────────────
+// DynamicRng::from_rng — caller-supplied generator
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "alloc")]
+// Deterministic counter "RNG" — marked `CryptoRng` for test purposes only.
+struct CounterRng(u8);
+
+#[cfg(feature = "alloc")]
+impl rand::RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        rand::rand_core::impls::next_u32_via_fill(self)
+    }
+    fn next_u64(&mut self) -> u64 {
+        rand::rand_core::impls::next_u64_via_fill(self)
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for b in dst {
+            *b = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl rand::CryptoRng for CounterRng {}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_rng_from_rng_uses_supplied_generator() {
+    let mut rng = CounterRng(10);
+    let a = DynamicRng::from_rng(&mut rng, 4);
+    assert_eq!(a.expose_secret(), &[10, 11, 12, 13]);
+
+    let b = DynamicRng::from_rng(&mut rng, 3);
+    assert_eq!(b.expose_secret(), &[14, 15, 16]);
+    assert!(DynamicRng::from_rng(&mut rng, 0).is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn dynamic_rng_from_rng_is_reproducible() {
+    let a = DynamicRng::from_rng(&mut CounterRng(0), 64);
+    let b = DynamicRng::from_rng(&mut CounterRng(0), 64);
+    assert_eq!(a.expose_secret(), b.expose_secret());
+}
+
+// ──────────────────────────────────────────────────────────────
+// Fixed::derive_from_seed — labeled deterministic derivation
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn derive_from_seed_same_label_reproduces() {
+    let seed = Fixed::new([7u8; 32]);
+    let a = Fixed::<[u8; 32]>::derive_from_seed(&seed, b"session");
+    let b = Fixed::<[u8; 32]>::derive_from_seed(&seed, b"session");
+    assert_eq!(a.expose_secret(), b.expose_secret());
+}
+
+#[test]
+fn derive_from_seed_separates_labels_and_seeds() {
+    let seed = Fixed::new([7u8; 32]);
+    let a = Fixed::<[u8; 32]>::derive_from_seed(&seed, b"enc");
+    let b = Fixed::<[u8; 32]>::derive_from_seed(&seed, b"mac");
+    let empty = Fixed::<[u8; 32]>::derive_from_seed(&seed, b"");
+    let other_seed = Fixed::<[u8; 32]>::derive_from_seed(&Fixed::new([8u8; 32]), b"enc");
+
+    assert_ne!(a.expose_secret(), b.expose_secret());
+    assert_ne!(a.expose_secret(), empty.expose_secret());
+    assert_ne!(a.expose_secret(), other_seed.expose_secret());
+}
+
+#[test]
+fn derive_from_seed_shorter_output_is_prefix() {
+    let seed = Fixed::new([1u8; 32]);
+    let long = Fixed::<[u8; 64]>::derive_from_seed(&seed, b"ctx");
+    let short = Fixed::<[u8; 16]>::derive_from_seed(&seed, b"ctx");
+    assert_eq!(&long.expose_secret()[..16], short.expose_secret());
+    assert_ne!(&long.expose_secret()[..32], &long.expose_secret()[32..]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Fixed::generate_distinct — pairwise-distinct random keys
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn generate_distinct_keys_all_differ() {
+    let keys = Fixed::<[u8; 32]>::generate_distinct::<16>();
+    for (i, a) in keys.iter().enumerate() {
+        for b in &keys[i + 1..] {
+            assert_ne!(a.expose_secret(), b.expose_secret());
+        }
+    }
+}
+
+#[test]
+fn generate_distinct_exhausts_tiny_key_space() {
+    // Every one-byte value exactly once: duplicates must be re-rolled, not kept
+    let keys = Fixed::<[u8; 1]>::generate_distinct::<256>();
+    let mut seen = [false; 256];
+    for k in &keys {
+        let b = k.expose_secret()[0] as usize;
+        assert!(!seen[b], "duplicate key {b:#04x}");
+        seen[b] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+
+    assert!(Fixed::<[u8; 4]>::generate_distinct::<0>().is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// EntropyPool — buffered OS entropy
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "alloc")]
+#[test]
+fn entropy_pool_output_is_uniform_across_refills() {
+    use secure_gate::rng::EntropyPool;
+
+    // 64 KiB drawn in odd-sized chunks crosses many refill boundaries
+    let mut pool = EntropyPool::new();
+    let mut counts = [0u32; 256];
+    let mut total = 0;
+    while total < 65_536 {
+        let chunk = pool.generate_dynamic(37);
+        for &b in chunk.expose_secret() {
+            counts[b as usize] += 1;
+        }
+        total += chunk.len();
+    }
+    // Expected ~256 per value (σ ≈ 16); anything outside this band is far beyond chance
+    for (value, &count) in counts.iter().enumerate() {
+        assert!((150..400).contains(&count), "byte {value:#04x} seen {count} times");
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn entropy_pool_never_repeats_tokens() {
+    use secure_gate::rng::EntropyPool;
+    use std::collections::HashSet;
+
+    let mut pool = EntropyPool::new();
+    let mut seen = HashSet::new();
+    for _ in 0..2_000 {
+        let token = pool.generate::<16>();
+        assert!(seen.insert(*token.expose_secret()), "duplicate token from pool");
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn entropy_pool_accounting_and_discard() {
+    use secure_gate::rng::EntropyPool;
+
+    let mut pool = EntropyPool::default();
+    assert_eq!(pool.remaining(), 0); // lazily filled
+    let _ = pool.generate::<32>();
+    assert_eq!(pool.remaining(), EntropyPool::CAPACITY - 32);
+
+    pool.discard();
+    assert_eq!(pool.remaining(), 0);
+
+    // Large requests bypass the buffer
+    let big = pool.generate_dynamic(EntropyPool::CAPACITY * 2);
+    assert_eq!(big.len(), EntropyPool::CAPACITY * 2);
+    assert_eq!(pool.remaining(), 0);
+    assert!(big.expose_secret().iter().any(|&b| b != 0));
+
+    assert_eq!(format!("{pool:?}"), "[REDACTED]");
+    assert_eq!(pool.try_generate::<8>().unwrap().len(), 8);
+}
+
+// `generate` itself is pooled per thread under `std`
+#[cfg(feature = "std")]
+#[test]
+fn thread_pooled_generate_is_uniform_and_unique() {
+    use secure_gate::rng::{discard_thread_pool, DynamicRng, FixedRng};
+    use std::collections::HashSet;
+
+    let mut seen = HashSet::new();
+    let mut counts = [0u32; 256];
+    for i in 0..4_096 {
+        if i == 2_048 {
+            discard_thread_pool();
+        }
+        let token = FixedRng::<16>::generate();
+        assert!(
+            seen.insert(*token.expose_secret()),
+            "duplicate token from thread pool"
+        );
+        for &b in DynamicRng::generate(16).expose_secret() {
+            counts[b as usize] += 1;
+        }
+    }
+    // 64 KiB through `DynamicRng::generate`; same band as the owned-pool test
+    for (value, &count) in counts.iter().enumerate() {
+        assert!(
+            (150..400).contains(&count),
+            "byte {value:#04x} seen {count} times"
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn thread_pools_are_independent() {
+    use secure_gate::rng::FixedRng;
+
+    let here = FixedRng::<32>::generate();
+    let there = std::thread::spawn(|| *FixedRng::<32>::generate().expose_secret())
+        .join()
+        .unwrap();
+    assert_ne!(here.expose_secret(), &there);
+}
+
+// ──────────────────────────────────────────────────────────────
+// keystream_blocks — ChaCha20 expansion of a fixed key
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "alloc")]
+#[test]
+fn keystream_blocks_match_chacha20_zero_key_vector() {
+    // ChaCha20, all-zero key and nonce, block counters 0 and 1
+    const BLOCK_0: [u8; 64] = [
+        0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86,
+        0xbd, 0x28, 0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc,
+        0x8b, 0x77, 0x0d, 0xc7, 0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24,
+        0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37, 0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c,
+        0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86,
+    ];
+    const BLOCK_1: [u8; 64] = [
+        0x9f, 0x07, 0xe7, 0xbe, 0x55, 0x51, 0x38, 0x7a, 0x98, 0xba, 0x97, 0x7c, 0x73, 0x2d,
+        0x08, 0x0d, 0xcb, 0x0f, 0x29, 0xa0, 0x48, 0xe3, 0x65, 0x69, 0x12, 0xc6, 0x53, 0x3e,
+        0x32, 0xee, 0x7a, 0xed, 0x29, 0xb7, 0x21, 0x76, 0x9c, 0xe6, 0x4e, 0x43, 0xd5, 0x71,
+        0x33, 0xb0, 0x74, 0xd8, 0x39, 0xd5, 0x31, 0xed, 0x1f, 0x28, 0x51, 0x0a, 0xfb, 0x45,
+        0xac, 0xe1, 0x0a, 0x1f, 0x4b, 0x79, 0x4d, 0x6f,
+    ];
+
+    let stream = Fixed::new([0u8; 32]).keystream_blocks(2);
+    assert_eq!(&stream.expose_secret()[..64], &BLOCK_0);
+    assert_eq!(&stream.expose_secret()[64..], &BLOCK_1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn keystream_blocks_deterministic_and_prefix_stable() {
+    let key = Fixed::new([0x5Au8; 32]);
+    let short = key.keystream_blocks(3);
+    let long = key.keystream_blocks(8);
+    assert_eq!(short.len(), 192);
+    assert_eq!(short.expose_secret(), key.keystream_blocks(3).expose_secret());
+    assert_eq!(short.expose_secret().as_slice(), &long.expose_secret()[..192]);
+
+    let other = Fixed::new([0x5Bu8; 32]).keystream_blocks(3);
+    assert_ne!(short.expose_secret(), other.expose_secret());
+    assert!(key.keystream_blocks(0).is_empty());
+}
@@ -0,0 +1,263 @@
+// ==========================================================================
+// tests/serde_tests.rs
+// ==========================================================================
+// Testing for opt-in serde support
+
+#![cfg(feature = "serde")]
+
+#[cfg(feature = "conversions")]
+use secure_gate::Fixed;
+
+// ──────────────────────────────────────────────────────────────
+// Fixed<[u8; N]> — human-readable vs binary
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_json_is_base64url() {
+    let key = Fixed::new([0xde, 0xad, 0xbe, 0xef]);
+    let json = serde_json::to_string(&key).unwrap();
+    assert_eq!(json, "\"3q2-7w\"");
+
+    let back: Fixed<[u8; 4]> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.expose_secret(), &[0xde, 0xad, 0xbe, 0xef]);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_bincode_is_raw_bytes() {
+    let key = Fixed::new([0x42u8; 32]);
+    let bin = bincode::serialize(&key).unwrap();
+    // u64 length prefix followed by the raw bytes — no base64 expansion
+    assert_eq!(bin.len(), 8 + 32);
+    assert_eq!(&bin[8..], &[0x42u8; 32]);
+
+    let back: Fixed<[u8; 32]> = bincode::deserialize(&bin).unwrap();
+    assert_eq!(back.expose_secret(), &[0x42u8; 32]);
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_json_wrong_length_rejected() {
+    let json = serde_json::to_string(&Fixed::new([1u8; 5])).unwrap();
+    assert!(serde_json::from_str::<Fixed<[u8; 4]>>(&json).is_err());
+    assert!(serde_json::from_str::<Fixed<[u8; 4]>>("\"not base64!\"").is_err());
+}
+
+#[cfg(feature = "conversions")]
+#[test]
+fn fixed_bincode_wrong_length_rejected() {
+    let bin = bincode::serialize(&Fixed::new([1u8; 5])).unwrap();
+    assert!(bincode::deserialize::<Fixed<[u8; 4]>>(&bin).is_err());
+}
+
+// ──────────────────────────────────────────────────────────────
+// Dynamic<T> — SerializableSecret opt-in (secrecy)
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "secrecy")]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+struct ApiToken(String);
+
+#[cfg(feature = "secrecy")]
+impl zeroize::Zeroize for ApiToken {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl secrecy::SerializableSecret for ApiToken {}
+
+#[cfg(feature = "secrecy")]
+#[test]
+fn dynamic_marked_json_roundtrip() {
+    let token = secure_gate::Dynamic::new(ApiToken("t0k3n".into()));
+    let json = serde_json::to_string(&token).unwrap();
+    assert_eq!(json, r#""t0k3n""#);
+
+    let back: secure_gate::Dynamic<ApiToken> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.expose_secret(), &ApiToken("t0k3n".into()));
+}
+
+#[cfg(feature = "secrecy")]
+#[test]
+fn dynamic_marked_wire_compatible_with_secret_box() {
+    use secrecy::ExposeSecret;
+
+    let ours = secure_gate::Dynamic::new(ApiToken("shared".into()));
+    let theirs = secrecy::SecretBox::new(Box::new(ApiToken("shared".into())));
+    assert_eq!(
+        serde_json::to_string(&ours).unwrap(),
+        serde_json::to_string(&theirs).unwrap()
+    );
+
+    let json = serde_json::to_string(&ours).unwrap();
+    let back: secrecy::SecretBox<ApiToken> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.expose_secret(), &ApiToken("shared".into()));
+}
+
+// ──────────────────────────────────────────────────────────────
+// Per-field redaction
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "alloc")]
+#[derive(serde::Serialize)]
+struct Credentials {
+    user: String,
+    #[serde(serialize_with = "secure_gate::serde::redact")]
+    password: secure_gate::Dynamic<String>,
+    #[serde(serialize_with = "secure_gate::serde::redact")]
+    key: secure_gate::Fixed<[u8; 4]>,
+    retries: u8,
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn redacted_fields_serialize_as_placeholder() {
+    let creds = Credentials {
+        user: "alice".into(),
+        password: "hunter2".into(),
+        key: secure_gate::Fixed::new([0xAA; 4]),
+        retries: 3,
+    };
+
+    let json = serde_json::to_string(&creds).unwrap();
+    assert_eq!(
+        json,
+        r#"{"user":"alice","password":"[REDACTED]","key":"[REDACTED]","retries":3}"#
+    );
+    assert!(!json.contains("hunter2"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn redacted_fields_are_format_agnostic_cbor() {
+    let creds = Credentials {
+        user: "alice".into(),
+        password: "hunter2".into(),
+        key: secure_gate::Fixed::new([0xAA; 4]),
+        retries: 3,
+    };
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&creds, &mut cbor).unwrap();
+    assert!(!cbor.windows(7).any(|w| w == b"hunter2"));
+    assert!(!cbor.windows(4).any(|w| w == [0xAA; 4]));
+
+    let value: ciborium::Value = ciborium::from_reader(cbor.as_slice()).unwrap();
+    let fields = value.as_map().unwrap();
+    let get = |name: &str| {
+        fields
+            .iter()
+            .find(|(k, _)| k.as_text() == Some(name))
+            .map(|(_, v)| v.clone())
+            .unwrap()
+    };
+    assert_eq!(get("password"), ciborium::Value::Text("[REDACTED]".into()));
+    assert_eq!(get("key"), ciborium::Value::Text("[REDACTED]".into()));
+    assert_eq!(get("user"), ciborium::Value::Text("alice".into()));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn redacted_fields_are_format_agnostic_bincode() {
+    let creds = Credentials {
+        user: "alice".into(),
+        password: "hunter2".into(),
+        key: secure_gate::Fixed::new([0xAA; 4]),
+        retries: 3,
+    };
+
+    let bytes = bincode::serialize(&creds).unwrap();
+    assert!(!bytes.windows(7).any(|w| w == b"hunter2"));
+    assert_eq!(bytes.windows(10).filter(|w| *w == b"[REDACTED]").count(), 2);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Sealed / SecureDeserializeSeed — authenticated Dynamic<Vec<u8>>
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+fn open_json(
+    key: &Fixed<[u8; 32]>,
+    json: &str,
+) -> Result<secure_gate::Dynamic<Vec<u8>>, serde_json::Error> {
+    use serde::de::DeserializeSeed;
+    let mut de = serde_json::Deserializer::from_str(json);
+    secure_gate::serde::SecureDeserializeSeed(key).deserialize(&mut de)
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+#[test]
+fn sealed_json_roundtrip() {
+    use secure_gate::serde::Sealed;
+
+    let key = Fixed::new([3u8; 32]);
+    let secret = secure_gate::Dynamic::<Vec<u8>>::new(b"top secret".to_vec());
+    let json = serde_json::to_string(&Sealed::new(&key, &secret)).unwrap();
+
+    let back = open_json(&key, &json).unwrap();
+    assert_eq!(back.expose_secret(), b"top secret");
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+#[test]
+fn sealed_bincode_roundtrip() {
+    use bincode::Options;
+    use secure_gate::serde::{Sealed, SecureDeserializeSeed};
+    use serde::de::DeserializeSeed;
+
+    let key = Fixed::new([4u8; 32]);
+    let secret = secure_gate::Dynamic::<Vec<u8>>::new(vec![0u8, 1, 2, 255]);
+    let opts = bincode::DefaultOptions::new();
+    let bin = opts.serialize(&Sealed::new(&key, &secret)).unwrap();
+
+    let mut de = bincode::Deserializer::from_slice(&bin, opts);
+    let back = SecureDeserializeSeed(&key).deserialize(&mut de).unwrap();
+    assert_eq!(back.expose_secret(), &[0, 1, 2, 255]);
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+#[test]
+fn sealed_rejects_tampering_and_wrong_key() {
+    use secure_gate::serde::Sealed;
+    use secure_gate::SecureConversionsExt;
+
+    let key = Fixed::new([5u8; 32]);
+    let secret = secure_gate::Dynamic::<Vec<u8>>::new(vec![10u8; 8]);
+    let json = serde_json::to_string(&Sealed::new(&key, &secret)).unwrap();
+
+    // Wrong key
+    assert!(open_json(&Fixed::new([6u8; 32]), &json).is_err());
+
+    // Flip one bit in the payload, then in the tag
+    let raw = Fixed::<[u8; 40]>::from_base64url(json.trim_matches('"')).unwrap();
+    for idx in [0, 39] {
+        let mut bytes = *raw.expose_secret();
+        bytes[idx] ^= 0x01;
+        let tampered = format!("\"{}\"", bytes.to_base64url());
+        assert!(open_json(&key, &tampered).is_err());
+    }
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+#[test]
+fn sealed_rejects_short_and_malformed_input() {
+    let key = Fixed::new([7u8; 32]);
+    assert!(open_json(&key, "\"\"").is_err());
+    assert!(open_json(&key, "\"AAAA\"").is_err());
+    assert!(open_json(&key, "\"not base64!\"").is_err());
+}
+
+#[cfg(all(feature = "conversions", feature = "alloc"))]
+#[test]
+fn sealed_empty_payload_roundtrip() {
+    use secure_gate::serde::Sealed;
+
+    let key = Fixed::new([8u8; 32]);
+    let secret = secure_gate::Dynamic::<Vec<u8>>::new(Vec::new());
+    let json = serde_json::to_string(&Sealed::new(&key, &secret)).unwrap();
+    assert!(open_json(&key, &json).unwrap().is_empty());
+    assert_eq!(format!("{:?}", Sealed::new(&key, &secret)), "[REDACTED]");
+}
@@ -0,0 +1,111 @@
+// ==========================================================================
+// tests/shared_tests.rs
+// ==========================================================================
+// Testing for Arc-shared read-only secrets
+
+#![cfg(feature = "alloc")]
+
+use secure_gate::SharedSecret;
+
+// ──────────────────────────────────────────────────────────────
+// Sharing
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn shared_clone_shares_the_buffer() {
+    let key = SharedSecret::new(vec![7u8; 32]);
+    let other = key.clone();
+
+    assert!(SharedSecret::ptr_eq(&key, &other));
+    assert_eq!(key.expose_secret().as_ptr(), other.expose_secret().as_ptr());
+    assert_eq!(SharedSecret::handle_count(&key), 2);
+
+    drop(other);
+    assert_eq!(SharedSecret::handle_count(&key), 1);
+}
+
+#[test]
+fn shared_separate_values_do_not_share() {
+    let a = SharedSecret::new(String::from("a"));
+    let b = SharedSecret::new(String::from("a"));
+    assert!(!SharedSecret::ptr_eq(&a, &b));
+}
+
+#[test]
+fn shared_debug_is_redacted() {
+    let key = SharedSecret::new(String::from("hunter2"));
+    assert_eq!(format!("{key:?}"), "[REDACTED]");
+}
+
+#[test]
+fn shared_across_threads() {
+    let key = SharedSecret::new(vec![1u8, 2, 3]);
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let key = key.clone();
+            std::thread::spawn(move || key.expose_secret().iter().map(|&b| b as u32).sum::<u32>())
+        })
+        .collect();
+    for h in handles {
+        assert_eq!(h.join().unwrap(), 6);
+    }
+    assert_eq!(SharedSecret::handle_count(&key), 1);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Wipe only after the last handle drops
+// ──────────────────────────────────────────────────────────────
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn shared_wipes_once_after_last_drop() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct Probe(Arc<AtomicUsize>);
+    impl zeroize::Zeroize for Probe {
+        fn zeroize(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let wipes = Arc::new(AtomicUsize::new(0));
+    let a = SharedSecret::new(Probe(wipes.clone()));
+    let b = a.clone();
+    let c = b.clone();
+
+    drop(a);
+    drop(c);
+    assert_eq!(wipes.load(Ordering::SeqCst), 0);
+
+    drop(b);
+    assert_eq!(wipes.load(Ordering::SeqCst), 1);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn shared_concurrent_drops_still_wipe_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct Probe(Arc<AtomicUsize>);
+    impl zeroize::Zeroize for Probe {
+        fn zeroize(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let wipes = Arc::new(AtomicUsize::new(0));
+    let secret = SharedSecret::new(Probe(wipes.clone()));
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let s = secret.clone();
+            std::thread::spawn(move || drop(s))
+        })
+        .collect();
+    drop(secret);
+    for h in handles {
+        h.join().unwrap();
+    }
+    assert_eq!(wipes.load(Ordering::SeqCst), 1);
+}
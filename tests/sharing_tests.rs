@@ -0,0 +1,82 @@
+// ==========================================================================
+// tests/sharing_tests.rs
+// ==========================================================================
+// Testing for XOR secret sharing
+
+#![cfg(all(feature = "rand", feature = "alloc"))]
+
+use secure_gate::Dynamic;
+
+// ──────────────────────────────────────────────────────────────
+// Round-trip
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn xor_shares_roundtrip() {
+    let secret = Dynamic::<Vec<u8>>::new((0u8..=255).collect::<Vec<u8>>());
+    for n in [1, 2, 3, 7] {
+        let shares = secret.split_xor_shares(n);
+        assert_eq!(shares.len(), n);
+        assert!(shares.iter().all(|s| s.len() == secret.len()));
+
+        let rebuilt = Dynamic::<Vec<u8>>::combine_xor_shares(&shares);
+        assert_eq!(rebuilt.expose_secret(), secret.expose_secret());
+    }
+}
+
+#[test]
+fn xor_shares_empty_secret() {
+    let secret = Dynamic::<Vec<u8>>::new(Vec::new());
+    let shares = secret.split_xor_shares(2);
+    assert!(Dynamic::<Vec<u8>>::combine_xor_shares(&shares).is_empty());
+}
+
+#[test]
+fn xor_shares_debug_redacted() {
+    let shares = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]).split_xor_shares(2);
+    for share in &shares {
+        assert_eq!(format!("{share:?}"), "[REDACTED]");
+    }
+}
+
+#[test]
+fn xor_shares_incomplete_set_does_not_rebuild() {
+    let secret = Dynamic::<Vec<u8>>::new(vec![0x42u8; 64]);
+    let shares = secret.split_xor_shares(3);
+    let partial = Dynamic::<Vec<u8>>::combine_xor_shares(&shares[..2]);
+    assert_ne!(partial.expose_secret(), secret.expose_secret());
+}
+
+// ──────────────────────────────────────────────────────────────
+// A single share reveals nothing (statistical)
+// ──────────────────────────────────────────────────────────────
+
+fn ones_ratio(bytes: &[u8]) -> f64 {
+    let ones: u32 = bytes.iter().map(|b| b.count_ones()).sum();
+    f64::from(ones) / (bytes.len() as f64 * 8.0)
+}
+
+#[test]
+fn xor_single_share_looks_random_for_constant_secret() {
+    // An all-zero or all-one secret would show through any share that leaked it
+    for fill in [0x00u8, 0xFF] {
+        let secret = Dynamic::<Vec<u8>>::new(vec![fill; 64 * 1024]);
+        for share in secret.split_xor_shares(2) {
+            let ratio = ones_ratio(share.expose_secret());
+            assert!((0.48..0.52).contains(&ratio), "ones ratio {ratio}");
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "at least one share")]
+fn xor_shares_zero_panics() {
+    let _ = Dynamic::<Vec<u8>>::new(vec![1]).split_xor_shares(0);
+}
+
+#[test]
+#[should_panic(expected = "equal lengths")]
+fn xor_combine_mismatched_lengths_panics() {
+    let shares = [Dynamic::<Vec<u8>>::new(vec![1, 2]), Dynamic::new(vec![3])];
+    let _ = Dynamic::<Vec<u8>>::combine_xor_shares(&shares);
+}
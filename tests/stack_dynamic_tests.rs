@@ -0,0 +1,118 @@
+// ==========================================================================
+// tests/stack_dynamic_tests.rs
+// ==========================================================================
+// Testing for fixed-capacity, variable-length stack secrets
+
+use secure_gate::StackDynamic;
+
+// ──────────────────────────────────────────────────────────────
+// Growth up to capacity
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn stack_dynamic_grows_to_capacity() {
+    let mut s = StackDynamic::<4>::new();
+    assert!(s.is_empty());
+    assert_eq!(s.capacity(), 4);
+    assert_eq!(StackDynamic::<4>::CAPACITY, 4);
+
+    s.push(1).unwrap();
+    s.extend_from_slice(&[2, 3]).unwrap();
+    assert_eq!(s.remaining(), 1);
+    s.push(4).unwrap();
+
+    assert_eq!(s.expose_secret(), &[1, 2, 3, 4]);
+    assert_eq!(s.len(), 4);
+    assert_eq!(s.remaining(), 0);
+}
+
+#[test]
+fn stack_dynamic_try_from_slice_exact_fit() {
+    let s = StackDynamic::<3>::try_from_slice(b"abc").unwrap();
+    assert_eq!(s.expose_secret(), b"abc");
+    assert!(StackDynamic::<0>::try_from_slice(&[]).unwrap().is_empty());
+}
+
+// ──────────────────────────────────────────────────────────────
+// Overflow handling
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn stack_dynamic_push_when_full_fails_unchanged() {
+    let mut s = StackDynamic::<2>::try_from_slice(&[7, 8]).unwrap();
+    assert!(s.push(9).is_err());
+    assert_eq!(s.expose_secret(), &[7, 8]);
+
+    assert!(StackDynamic::<0>::new().push(1).is_err());
+}
+
+#[test]
+fn stack_dynamic_extend_is_all_or_nothing() {
+    let mut s = StackDynamic::<4>::try_from_slice(&[1, 2]).unwrap();
+    assert_eq!(s.extend_from_slice(&[3, 4, 5]), Err("StackDynamic capacity exceeded"));
+    assert_eq!(s.expose_secret(), &[1, 2]);
+
+    s.extend_from_slice(&[]).unwrap();
+    assert!(StackDynamic::<4>::try_from_slice(&[0; 5]).is_err());
+}
+
+// ──────────────────────────────────────────────────────────────
+// Shrinking wipes the vacated bytes
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn stack_dynamic_truncate_wipes_tail() {
+    let mut s = StackDynamic::<6>::try_from_slice(b"secret").unwrap();
+    s.truncate(2);
+    assert_eq!(s.expose_secret(), b"se");
+
+    // Regrowing must not resurrect the old bytes
+    let mut longer = s.clone();
+    longer.push(b'!').unwrap();
+    assert_eq!(longer.expose_secret(), b"se!");
+
+    s.truncate(10); // no-op when not shorter
+    assert_eq!(s.len(), 2);
+    s.clear();
+    assert!(s.is_empty());
+}
+
+#[test]
+fn stack_dynamic_expose_secret_mut_edits_in_place() {
+    let mut s = StackDynamic::<8>::try_from_slice(&[1, 2, 3]).unwrap();
+    s.expose_secret_mut()[0] = 9;
+    assert_eq!(s.expose_secret_mut().len(), 3);
+    assert_eq!(s.expose_secret(), &[9, 2, 3]);
+}
+
+// ──────────────────────────────────────────────────────────────
+// Debug, Clone, zeroize
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn stack_dynamic_debug_is_redacted() {
+    let s = StackDynamic::<8>::try_from_slice(b"hunter2").unwrap();
+    assert_eq!(format!("{s:?}"), "[REDACTED]");
+    assert_eq!(format!("{:?}", StackDynamic::<8>::default()), "[REDACTED]");
+}
+
+#[test]
+fn stack_dynamic_clone_is_independent() {
+    let a = StackDynamic::<4>::try_from_slice(&[1, 2]).unwrap();
+    let mut b = a.clone();
+    b.push(3).unwrap();
+    assert_eq!(a.expose_secret(), &[1, 2]);
+    assert_eq!(b.expose_secret(), &[1, 2, 3]);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn stack_dynamic_zeroize_clears_contents() {
+    use zeroize::Zeroize;
+
+    let mut s = StackDynamic::<4>::try_from_slice(&[0xAA; 4]).unwrap();
+    s.zeroize();
+    assert!(s.is_empty());
+    s.extend_from_slice(&[0, 0, 0, 0]).unwrap();
+    assert_eq!(s.expose_secret(), &[0; 4]);
+}
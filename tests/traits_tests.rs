@@ -0,0 +1,57 @@
+// ==========================================================================
+// tests/traits_tests.rs
+// ==========================================================================
+// Testing for traits shared across wrapper families
+
+use secure_gate::{CloneSecret, Fixed};
+
+fn duplicate<S: CloneSecret>(secret: &S) -> (S, S) {
+    (secret.clone_secret(), secret.clone_secret())
+}
+
+// ──────────────────────────────────────────────────────────────
+// CloneSecret
+// ──────────────────────────────────────────────────────────────
+
+#[test]
+fn clone_secret_generic_over_fixed() {
+    let key = Fixed::new([0x42u8; 32]);
+    let (a, mut b) = duplicate(&key);
+    assert_eq!(a.expose_secret(), key.expose_secret());
+
+    b.expose_secret_mut()[0] = 0;
+    assert_eq!(key.expose_secret()[0], 0x42); // independent copies
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn clone_secret_generic_over_dynamic() {
+    use secure_gate::Dynamic;
+
+    let pw: Dynamic<String> = "hunter2".into();
+    let (a, mut b) = duplicate(&pw);
+    assert_eq!(a.expose_secret(), "hunter2");
+
+    b.expose_secret_mut().push('!');
+    assert_eq!(pw.expose_secret(), "hunter2");
+    assert_ne!(a.expose_secret().as_ptr(), pw.expose_secret().as_ptr());
+
+    let bytes = Dynamic::<Vec<u8>>::new(vec![1, 2, 3]);
+    assert_eq!(bytes.clone_secret().expose_secret(), &[1, 2, 3]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn clone_secret_mixed_collection() {
+    use secure_gate::Dynamic;
+
+    fn clone_all<S: CloneSecret>(items: &[S]) -> Vec<S> {
+        items.iter().map(CloneSecret::clone_secret).collect()
+    }
+
+    let keys = [Fixed::new([1u8; 16]), Fixed::new([2u8; 16])];
+    assert_eq!(clone_all(&keys)[1].expose_secret(), &[2u8; 16]);
+
+    let tokens: Vec<Dynamic<Vec<u8>>> = vec![vec![9u8].into(), vec![8u8].into()];
+    assert_eq!(clone_all(&tokens)[0].expose_secret(), &[9]);
+}